@@ -3,13 +3,15 @@
 //! Its' main struct is [`Auction`] which defines the bridge auction state machine. See its documentation for an usage example.
 
 use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
 use num_traits::FromPrimitive;
 
 use constants::*;
 
 use crate::contract::{BidContract, Contract, ContractLevel, Modifier, Strain};
-use crate::{turns, BridgeDirection};
+use crate::{seat_order, turns, BridgeDirection, Partnership};
 
 /// A bridge auction state machine
 ///
@@ -38,6 +40,7 @@ pub struct Auction {
     bids: Vec<Bid>,
     last_strain_bid: Option<StrainBid>,
     last_bidder: Option<BridgeDirection>,
+    alerts: Vec<(usize, String)>,
 }
 
 impl Auction {
@@ -50,25 +53,32 @@ impl Auction {
             bids: vec![],
             last_strain_bid: None,
             last_bidder: None,
+            alerts: vec![],
         }
     }
 
     /// Represents a bid made by the current player.
     ///
-    /// Returns `Ok(())` if the bid is sufficient and accepted. It returns an `auction::Error` variant otherwise.
+    /// Returns `Ok(())` if the bid is sufficient and accepted. It returns an `auction::Error` variant otherwise, e.g. [`Error::AuctionCompleted`] for any bid once the auction is over.
     /// # Example:
     /// ```
     /// # use bridge_backend::{Auction, BridgeDirection};
-    /// # use bridge_backend::auction::constants::*;
+    /// # use bridge_backend::auction::{Error, constants::*};
+    /// # fn main() -> Result<(), Error> {
     /// let mut auction = Auction::new(BridgeDirection::S);
     /// auction.bid(TWO_SPADES)?;
     /// auction.bid(DOUBLE)?;
     /// auction.bid(REDOUBLE)?;
     /// auction.bid(PASS)?;
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// Note: By definition, the bid is made by the player whose turn it is. Out of turn bids are impossible to model.
     pub fn bid(&mut self, bid: Bid) -> Result<(), Error> {
+        if self.is_completed() {
+            return Err(Error::AuctionCompleted);
+        }
         match bid {
             PASS => Ok(self.bids.push(bid)),
             Bid::RealBid(real_bid) => {
@@ -97,11 +107,110 @@ impl Auction {
         }
     }
 
+    /// Represents a bid made by the current player, along with an optional alert explaining its meaning.
+    ///
+    /// The alert is only recorded, see [`alerts`](Auction::alerts): it has no effect on the legality of bids or on the contract. Returns the same errors as [`bid`](Auction::bid), in which case the alert is dropped.
+    pub fn bid_with_alert(&mut self, bid: Bid, alert: Option<String>) -> Result<(), Error> {
+        self.bid(bid)?;
+        if let Some(alert) = alert {
+            self.alerts.push((self.bids.len() - 1, alert));
+        }
+        Ok(())
+    }
+
+    /// Returns the explanations of the alerted bids, in order, each with the position of its bid in the auction.
+    pub fn alerts(&self) -> Vec<(usize, &str)> {
+        self.alerts
+            .iter()
+            .map(|(index, alert)| (*index, alert.as_str()))
+            .collect()
+    }
+
+    /// Takes back the last recorded bid, returning it, or `None` if no bid was made yet.
+    ///
+    /// The highest strain bid and its bidder are recomputed from the remaining bids, so the auction behaves as if the undone bid had never been made. Its alert, if any, is dropped too.
+    pub fn undo_last_bid(&mut self) -> Option<Bid> {
+        let undone = self.bids.pop()?;
+        if let Some(&(index, _)) = self.alerts.last() {
+            if index == self.bids.len() {
+                self.alerts.pop();
+            }
+        }
+        let last_strain_bid = self
+            .bids
+            .iter()
+            .zip(turns(self.dealer))
+            .filter_map(|(bid, bidder)| match bid {
+                Bid::RealBid(strain_bid) => Some((*strain_bid, bidder)),
+                _ => None,
+            })
+            .last();
+        self.last_strain_bid = last_strain_bid.map(|(strain_bid, _)| strain_bid);
+        self.last_bidder = last_strain_bid.map(|(_, bidder)| bidder);
+        Some(undone)
+    }
+
+    /// Represents a bid made by a specific player.
+    ///
+    /// Fails with [`Error::NotYourTurn`] if `who` is not the [`current_bidder`](Auction::current_bidder), leaving the auction untouched. Otherwise it behaves like [`bid`](Auction::bid).
+    pub fn bid_by(&mut self, who: BridgeDirection, bid: Bid) -> Result<(), Error> {
+        if self.current_bidder() != Some(who) {
+            return Err(Error::NotYourTurn);
+        }
+        self.bid(bid)
+    }
+
+    /// Parses an auction written in the notation of [`to_notation`](Auction::to_notation), starting with `dealer`.
+    ///
+    /// Every bid is replayed through [`bid`](Auction::bid), so a string describing an illegal auction is rejected with the same error. Bids that can't be parsed are reported as [`Error::InvalidNotation`], and bids that follow the end of the auction as [`Error::AuctionCompleted`].
+    /// # Example:
+    /// ```
+    /// # use bridge_backend::{Auction, BridgeDirection};
+    /// # use bridge_backend::auction::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let auction = Auction::from_notation(BridgeDirection::N, "1C-P-1H-P;1NT-P-3NT-P-P-P")?;
+    /// assert!(auction.is_completed());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_notation(dealer: BridgeDirection, notation: &str) -> Result<Auction, Error> {
+        let mut auction = Auction::new(dealer);
+        for bid in notation
+            .split(&['-', ';'][..])
+            .filter(|bid| !bid.is_empty())
+        {
+            auction.bid(bid.parse().map_err(Error::InvalidNotation)?)?;
+        }
+        Ok(auction)
+    }
+
+    /// Writes the bids made so far compactly, e.g. `1C-P-1H-P;1NT-P-3NT-P;P-P`.
+    ///
+    /// Bids are separated by dashes, with a semicolon after every round of four. The dealer isn't included.
+    pub fn to_notation(&self) -> String {
+        self.bids
+            .chunks(4)
+            .map(|round| {
+                round
+                    .iter()
+                    .map(Bid::to_string)
+                    .collect::<Vec<_>>()
+                    .join("-")
+            })
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
     /// The auction is finished after everyone has bid at least once, and the last three bids were passes.
     pub fn is_completed(&self) -> bool {
         self.bids.len() >= 4 && self.bids.iter().rev().take(3).all(|&b| b == PASS)
     }
 
+    /// Returns true if the auction consists of exactly four passes, and no strain was ever bid.
+    pub fn is_passed_out(&self) -> bool {
+        self.bids.len() == 4 && !self.has_real_bid()
+    }
+
     /// Returns true if there's any other recorded bid but PASS.
     pub fn has_real_bid(&self) -> bool {
         self.bids.iter().any(|&b| b != PASS)
@@ -118,7 +227,7 @@ impl Auction {
     /// Ensures that `DOUBLE` is a valid bid.
     fn can_double(&self) -> bool {
         if let Some(Bid::RealBid(_)) = self.last_meaningful_bid() {
-            self.trailing_passes() != 1 // Can't double partner
+            self.consecutive_passes() != 1 // Can't double partner
         } else {
             false
         }
@@ -127,7 +236,7 @@ impl Auction {
     /// Ensures that `REDOUBLE` is a valid bid.
     fn can_redouble(&self) -> bool {
         if let Some(DOUBLE) = self.last_meaningful_bid() {
-            self.trailing_passes() != 1 // Can't redouble partner
+            self.consecutive_passes() != 1 // Can't redouble partner
         } else {
             false
         }
@@ -138,20 +247,62 @@ impl Auction {
         self.bids.iter().rev().find(|&&b| b != PASS).cloned()
     }
 
-    fn trailing_passes(&self) -> usize {
+    /// Returns the number of passes made since the last bid, double or redouble.
+    pub fn consecutive_passes(&self) -> usize {
         self.bids.iter().rev().take_while(|&&b| b == PASS).count()
     }
 
+    /// Returns true if the player on turn is in the balancing seat: the opponents hold the highest bid, followed by two passes, so passing would end the auction.
+    pub fn is_balancing_seat(&self) -> bool {
+        match (self.current_bidder(), self.last_bidder) {
+            (Some(who), Some(last_bidder)) => {
+                self.consecutive_passes() == 2 && who.is_opponent_of(last_bidder)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the player who must make the next call, or `None` once the auction is completed.
+    pub fn current_bidder(&self) -> Option<BridgeDirection> {
+        if self.is_completed() {
+            None
+        } else {
+            Some(self.whose_turn_is_it())
+        }
+    }
+
     fn whose_turn_is_it(&self) -> BridgeDirection {
-        let delta = self.bids.len() % 4;
-        turns(self.dealer).skip(delta).next().unwrap()
+        seat_order(self.dealer)[self.bids.len() % 4]
+    }
+
+    /// Returns every bid made so far, paired with the player who made it, starting with the dealer.
+    pub fn history(&self) -> Vec<(BridgeDirection, Bid)> {
+        turns(self.dealer).zip(self.bids.iter().cloned()).collect()
+    }
+
+    /// Returns the player who would declare the highest bid so far, i.e. the member of its side who first named its strain.
+    ///
+    /// Unlike [`contract`](Auction::contract), this is available while the auction is still in progress, so the declarer it reports may still change. Returns `None` if no strain was bid yet.
+    pub fn declarer(&self) -> Option<BridgeDirection> {
+        let strain = self.last_strain_bid?.strain;
+        let side = Partnership::of(self.last_bidder?);
+        self.first_to_bid_strain(strain, side)
+    }
+
+    /// Returns the strain of the highest bid so far, or `None` if no strain was bid yet.
+    ///
+    /// Like [`declarer`](Auction::declarer), this is available while the auction is still in progress.
+    pub fn final_strain(&self) -> Option<Strain> {
+        self.last_strain_bid.map(|bid| bid.strain)
     }
 
     /// Returns the `Contract` resulting from the `Auction`, when the auction is complete.
     pub fn contract(&self) -> Option<Contract> {
-        if self.is_completed() {
+        if self.is_passed_out() {
+            Some(Contract::PassedOut)
+        } else if self.is_completed() {
             match self.last_strain_bid {
-                None => Some(Contract::PassedOut),
+                None => None,
                 Some(contract) => {
                     let modifier: Modifier = match self
                         .last_meaningful_bid()
@@ -161,25 +312,9 @@ impl Auction {
                         Bid::Other(modifier) => modifier,
                     };
 
-                    let contract_set_by = self
-                        .last_bidder
-                        .expect("Bids have been made, we should have a bidder");
-                    let declarer: BridgeDirection = self
-                        .bids
-                        .iter()
-                        .zip(turns(self.dealer))
-                        .filter_map(|(bid, bidder)| match bid {
-                            Bid::RealBid(StrainBid { strain, .. })
-                                if *strain == contract.strain =>
-                            {
-                                Some(bidder)
-                            }
-                            _ => None,
-                        })
-                        .find(|&bidder| {
-                            bidder == contract_set_by || bidder == contract_set_by.partner()
-                        })
-                        .expect("Contracts tend to have a declarer");
+                    let declarer = self
+                        .declarer()
+                        .expect("Bids have been made, we should have a declarer");
 
                     Some(Contract::BidContract(BidContract {
                         contract,
@@ -192,6 +327,105 @@ impl Auction {
             None
         }
     }
+
+    /// Returns the contract to be played, once the auction is complete.
+    ///
+    /// Unlike [`contract`](Auction::contract), this returns `None` for a passed out board, since there's nothing to play.
+    pub fn bid_contract(&self) -> Option<BidContract> {
+        match self.contract()? {
+            Contract::PassedOut => None,
+            Contract::BidContract(contract) => Some(contract),
+        }
+    }
+
+    /// Returns every call the player whose turn it is may legally make.
+    ///
+    /// The sufficient strain bids come first, in ascending order, followed by `PASS`, then `DOUBLE` or `REDOUBLE` when allowed. Once the auction is completed, there are no legal calls left.
+    pub fn legal_bids(&self) -> Vec<Bid> {
+        if self.is_completed() {
+            return vec![];
+        }
+
+        let mut bids: Vec<Bid> = ContractLevel::all()
+            .flat_map(|level| Strain::all().map(move |strain| StrainBid { level, strain }))
+            .filter(|&strain_bid| self.is_bid_sufficient(strain_bid))
+            .map(Bid::RealBid)
+            .collect();
+        bids.push(PASS);
+        if self.can_double() {
+            bids.push(DOUBLE);
+        }
+        if self.can_redouble() {
+            bids.push(REDOUBLE);
+        }
+        bids
+    }
+
+    /// Returns the legal calls, each paired with a label suitable for a bidding box button, e.g. `"1♠"`, `"3NT"`, `"Pass"`, `"Dbl"` or `"Rdbl"`.
+    pub fn bidding_box(&self) -> Vec<(Bid, String)> {
+        self.legal_bids()
+            .into_iter()
+            .map(|bid| (bid, bid.label()))
+            .collect()
+    }
+
+    /// Returns true if the bid would be a cue bid, i.e. a bid in a suit already named by the opponents of the player whose turn it is.
+    ///
+    /// Cue bids are usually artificial. Notrump bids, passes, doubles and redoubles are never cue bids.
+    pub fn is_cue_bid(&self, bid: Bid) -> bool {
+        let strain = match bid {
+            Bid::RealBid(StrainBid { strain, .. }) if strain != Strain::NoTrump => strain,
+            _ => return false,
+        };
+        let opponents = Partnership::of(self.whose_turn_is_it()).opponents();
+        self.first_to_bid_strain(strain, opponents).is_some()
+    }
+
+    /// Returns true if the bid is a jump, i.e. it skips at least one level above the cheapest sufficient bid in its strain.
+    ///
+    /// With no previous strain bid, the cheapest bid is at the one level, so a two-level opening is a jump. Passes, doubles and redoubles are never jumps.
+    pub fn is_jump(&self, bid: Bid) -> bool {
+        let bid = match bid {
+            Bid::RealBid(strain_bid) => strain_bid,
+            Bid::Other(_) => return false,
+        };
+        let cheapest = ContractLevel::all()
+            .map(|level| StrainBid {
+                level,
+                strain: bid.strain,
+            })
+            .find(|&cheapest| self.is_bid_sufficient(cheapest));
+        match cheapest {
+            Some(cheapest) => bid.level > cheapest.level,
+            None => false,
+        }
+    }
+
+    /// Returns the member of a side who first named a specific strain.
+    ///
+    /// This is the rule used to determine the declarer: of the side winning the auction, the player who first bid the final strain plays the contract.
+    /// Returns `None` if the side never bid that strain.
+    pub fn first_to_bid_strain(
+        &self,
+        strain: Strain,
+        side: Partnership,
+    ) -> Option<BridgeDirection> {
+        self.bids
+            .iter()
+            .zip(turns(self.dealer))
+            .filter_map(|(bid, bidder)| match bid {
+                Bid::RealBid(strain_bid) if strain_bid.strain == strain => Some(bidder),
+                _ => None,
+            })
+            .find(|&bidder| side.contains(bidder))
+    }
+}
+
+impl Default for Auction {
+    /// Starts a new auction with North as the dealer. Use [`Auction::new`] to pick the dealer.
+    fn default() -> Self {
+        Self::new(BridgeDirection::N)
+    }
 }
 
 /// Represents a bid made by any player.
@@ -208,6 +442,56 @@ pub enum Bid {
     Other(Modifier),
 }
 
+impl Bid {
+    /// A short label for the bid, using suit symbols.
+    fn label(&self) -> String {
+        match self {
+            Bid::RealBid(StrainBid { level, strain }) => {
+                let strain = match strain {
+                    Strain::Clubs => "♣",
+                    Strain::Diamonds => "♦",
+                    Strain::Hearts => "♥",
+                    Strain::Spades => "♠",
+                    Strain::NoTrump => "NT",
+                };
+                format!("{}{}", *level as u8, strain)
+            }
+            Bid::Other(Modifier::Pass) => "Pass".to_string(),
+            Bid::Other(Modifier::Double) => "Dbl".to_string(),
+            Bid::Other(Modifier::Redouble) => "Rdbl".to_string(),
+        }
+    }
+}
+
+/// Displays the bid as written on a scoresheet: `1C` or `3NT` for strain bids, and `P`, `X` or `XX` for the others.
+impl fmt::Display for Bid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bid::RealBid(strain_bid) => write!(f, "{}", strain_bid),
+            Bid::Other(Modifier::Pass) => write!(f, "P"),
+            Bid::Other(Modifier::Double) => write!(f, "X"),
+            Bid::Other(Modifier::Redouble) => write!(f, "XX"),
+        }
+    }
+}
+
+/// Parses a bid in the format used by its `Display` implementation, ignoring case.
+impl FromStr for Bid {
+    type Err = BidParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("P") {
+            Ok(PASS)
+        } else if s.eq_ignore_ascii_case("X") {
+            Ok(DOUBLE)
+        } else if s.eq_ignore_ascii_case("XX") {
+            Ok(REDOUBLE)
+        } else {
+            StrainBid::try_from(s).map(Bid::RealBid)
+        }
+    }
+}
+
 /// Represents the bid of a strain by a player. Usually used through one of the named constants, e.g. [`ONE_CLUB`]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct StrainBid {
@@ -215,31 +499,61 @@ pub struct StrainBid {
     pub(crate) strain: Strain,
 }
 
+impl StrainBid {
+    /// Returns the lowest bid that is sufficient over this one, e.g. `1D` over `1C`, or `2C` over `1NT`. There is no bid higher than `7NT`.
+    pub fn next_higher(&self) -> Option<StrainBid> {
+        match Strain::all().find(|&strain| strain > self.strain) {
+            Some(strain) => Some(StrainBid {
+                level: self.level,
+                strain,
+            }),
+            None => FromPrimitive::from_u8(self.level as u8 + 1).map(|level| StrainBid {
+                level,
+                strain: Strain::Clubs,
+            }),
+        }
+    }
+}
+
+/// Displays the bid as written on a scoresheet, e.g. `1C` or `3NT`.
+impl fmt::Display for StrainBid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.level as u8, self.strain)
+    }
+}
+
+/// Parses a bid written as a level followed by a strain, e.g. `1c` or `3NT`, ignoring case.
+///
+/// Notrump may be written as either `N` or `NT`. Anything after the strain is rejected.
 impl TryFrom<&str> for StrainBid {
-    type Error = &'static str;
+    type Error = BidParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut chars = value.bytes();
-        let level = chars
-            .next()
-            .map(|code| code - '0' as u8)
+        let mut chars = value
+            .bytes()
+            .map(|code| code.to_ascii_uppercase())
+            .peekable();
+        let level = chars.next().ok_or(BidParseError::TooShort)?;
+        let level = level
+            .checked_sub(b'0')
             .and_then(FromPrimitive::from_u8)
-            .ok_or("Should be between 1 and 7")?;
-
-        let strain = chars
-            .next()
-            .map(char::from)
-            .as_ref()
-            .map(char::to_ascii_uppercase)
-            .and_then(|c| match c {
-                'N' => Some(Strain::NoTrump),
-                'S' => Some(Strain::Spades),
-                'H' => Some(Strain::Hearts),
-                'D' => Some(Strain::Diamonds),
-                'C' => Some(Strain::Clubs),
-                _ => None,
-            })
-            .ok_or("Should be either a suit or notrump")?;
+            .filter(|_| !matches!(chars.peek(), Some(next) if next.is_ascii_digit()))
+            .ok_or(BidParseError::BadLevel)?;
+
+        let strain = match chars.next().ok_or(BidParseError::TooShort)? {
+            b'N' => Strain::NoTrump,
+            b'S' => Strain::Spades,
+            b'H' => Strain::Hearts,
+            b'D' => Strain::Diamonds,
+            b'C' => Strain::Clubs,
+            _ => return Err(BidParseError::BadStrain),
+        };
+        if strain == Strain::NoTrump && chars.peek() == Some(&b'T') {
+            chars.next();
+        }
+        if chars.next().is_some() {
+            return Err(BidParseError::TrailingGarbage);
+        }
 
         Ok(Self { level, strain })
     }
@@ -247,6 +561,35 @@ impl TryFrom<&str> for StrainBid {
 
 pub mod constants;
 
+/// These are possible errors arising from reading a bid, e.g. with [`StrainBid::try_from`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BidParseError {
+    /// The bid doesn't start with a level between 1 and 7
+    BadLevel,
+
+    /// The level isn't followed by a suit or notrump
+    BadStrain,
+
+    /// The bid is empty, or stops after the level
+    TooShort,
+
+    /// Something follows the strain
+    TrailingGarbage,
+}
+
+impl fmt::Display for BidParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BidParseError::BadLevel => "the level should be between 1 and 7",
+            BidParseError::BadStrain => "the strain should be one of C, D, H, S or NT",
+            BidParseError::TooShort => "a bid should be a level followed by a strain, e.g. 1C",
+            BidParseError::TrailingGarbage => "nothing should follow the strain",
+        })
+    }
+}
+
+impl std::error::Error for BidParseError {}
+
 /// These are possible errors arising from trying to make a bid.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -303,6 +646,51 @@ pub enum Error {
     /// # }
     /// ```
     CantRedouble,
+
+    /// A bid was attempted by a player who is not on turn
+    ///
+    /// # Example:
+    /// ```should_panic
+    /// # use bridge_backend::{Auction, BridgeDirection};
+    /// # use bridge_backend::auction::{Error, constants::*};
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut auction = Auction::new(BridgeDirection::S);
+    ///
+    /// // South is the dealer, West can't bid first
+    /// auction.bid_by(BridgeDirection::W, ONE_CLUB)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    NotYourTurn,
+
+    /// A bid of a stored auction couldn't be read, see [`Auction::from_notation`]
+    InvalidNotation(BidParseError),
+
+    /// A bid was made after the end of the auction
+    AuctionCompleted,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InsufficientBid => f.write_str("insufficient bid"),
+            Error::CantDouble => f.write_str("cannot double here"),
+            Error::CantRedouble => f.write_str("cannot redouble here"),
+            Error::NotYourTurn => f.write_str("not your turn to bid"),
+            Error::InvalidNotation(error) => write!(f, "invalid auction notation: {}", error),
+            Error::AuctionCompleted => f.write_str("the auction is already over"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidNotation(error) => Some(error),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
 use crate::auction::constants::*;
 use crate::auction::Error::InsufficientBid;
-use crate::auction::{Auction, Error};
+use crate::auction::{Auction, Bid, BidParseError, Error};
 use crate::contract::Contract::PassedOut;
 use crate::contract::{ContractLevel, Strain};
-use crate::BridgeDirection;
+use crate::{BridgeDirection, Partnership};
+use std::error::Error as _;
 
 #[test]
 fn can_pass_out() -> Result<(), Error> {
@@ -22,6 +23,71 @@ fn can_pass_out() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn three_passes_then_a_bid() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::W);
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(ONE_CLUB)?;
+    assert!(!auction.is_completed());
+    assert!(auction.contract().is_none());
+
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert!(!auction.is_completed());
+    auction.bid(PASS)?;
+    assert!(auction.is_completed());
+
+    Ok(())
+}
+
+#[test]
+fn passed_out() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::E);
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert!(!auction.is_passed_out());
+    auction.bid(PASS)?;
+    assert!(auction.is_passed_out());
+
+    // A fifth pass, or any other bid, comes after the end of the auction
+    assert_eq!(auction.bid(PASS), Err(Error::AuctionCompleted));
+    assert_eq!(auction.bid(ONE_CLUB), Err(Error::AuctionCompleted));
+    assert!(auction.is_passed_out());
+
+    let mut auction = Auction::new(BridgeDirection::E);
+    auction.bid(ONE_CLUB)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert!(!auction.is_passed_out());
+
+    Ok(())
+}
+
+#[test]
+fn bid_contract() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::S);
+    auction.bid(ONE_NOTRUMP)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert_eq!(auction.bid_contract(), None);
+    auction.bid(PASS)?;
+    let contract = auction.bid_contract().expect("The auction is complete");
+    assert_eq!(contract.strain(), Strain::NoTrump);
+    assert_eq!(contract.declarer, BridgeDirection::S);
+
+    let mut auction = Auction::new(BridgeDirection::S);
+    for _ in 0..4 {
+        auction.bid(PASS)?;
+    }
+    assert_eq!(auction.bid_contract(), None);
+
+    Ok(())
+}
+
 #[test]
 fn can_bid_strain() -> Result<(), Error> {
     let mut auction = Auction::new(BridgeDirection::S);
@@ -86,7 +152,9 @@ fn doubles() -> Result<(), Error> {
     auction.bid(PASS)?;
     assert_eq!(auction.bid(DOUBLE).unwrap_err(), Error::CantDouble);
     auction.bid(PASS)?;
+    assert!(auction.is_balancing_seat());
     auction.bid(DOUBLE)?; // This works, it's a reveil
+    assert!(!auction.is_balancing_seat());
 
     // Auction can't start with a double either
     let mut auction = Auction::new(BridgeDirection::S);
@@ -156,6 +224,471 @@ fn auction_finished() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn first_to_bid_strain() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid(ONE_HEART)?;
+    auction.bid(ONE_SPADE)?;
+    auction.bid(TWO_HEARTS)?;
+    auction.bid(PASS)?;
+    auction.bid(THREE_CLUBS)?;
+    auction.bid(PASS)?;
+    auction.bid(FOUR_HEARTS)?;
+
+    assert_eq!(
+        auction.first_to_bid_strain(Strain::Hearts, Partnership::NS),
+        Some(BridgeDirection::N)
+    );
+    assert_eq!(
+        auction.first_to_bid_strain(Strain::Clubs, Partnership::NS),
+        Some(BridgeDirection::N)
+    );
+    assert_eq!(
+        auction.first_to_bid_strain(Strain::Spades, Partnership::EW),
+        Some(BridgeDirection::E)
+    );
+    assert_eq!(
+        auction.first_to_bid_strain(Strain::Hearts, Partnership::EW),
+        None
+    );
+    assert_eq!(
+        auction.first_to_bid_strain(Strain::Spades, Partnership::NS),
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn legal_bids() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    let bids = auction.legal_bids();
+    assert_eq!(bids.len(), 36);
+    assert_eq!(bids[0], ONE_CLUB);
+    assert_eq!(bids[34], SEVEN_NOTRUMP);
+    assert_eq!(bids[35], PASS);
+
+    auction.bid(SIX_NOTRUMP)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert_eq!(
+        auction.legal_bids(),
+        [
+            SEVEN_CLUBS,
+            SEVEN_DIAMONDS,
+            SEVEN_HEARTS,
+            SEVEN_SPADES,
+            SEVEN_NOTRUMP,
+            PASS,
+            DOUBLE
+        ]
+    );
+
+    auction.bid(DOUBLE)?;
+    assert_eq!(
+        auction.legal_bids(),
+        [
+            SEVEN_CLUBS,
+            SEVEN_DIAMONDS,
+            SEVEN_HEARTS,
+            SEVEN_SPADES,
+            SEVEN_NOTRUMP,
+            PASS,
+            REDOUBLE
+        ]
+    );
+
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert!(auction.legal_bids().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn provisional_declarer() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::E);
+    assert_eq!(auction.declarer(), None);
+    assert_eq!(auction.final_strain(), None);
+
+    auction.bid(PASS)?;
+    auction.bid(ONE_HEART)?;
+    assert_eq!(auction.declarer(), Some(BridgeDirection::S));
+    assert_eq!(auction.final_strain(), Some(Strain::Hearts));
+
+    auction.bid(ONE_SPADE)?;
+    auction.bid(TWO_HEARTS)?;
+    assert_eq!(auction.declarer(), Some(BridgeDirection::S));
+    auction.bid(TWO_SPADES)?;
+    assert_eq!(auction.declarer(), Some(BridgeDirection::W));
+    assert_eq!(auction.final_strain(), Some(Strain::Spades));
+
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert_eq!(auction.declarer(), Some(BridgeDirection::W));
+    assert_eq!(
+        auction.bid_contract().map(|contract| contract.declarer),
+        auction.declarer()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bidding_box() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    let opening_box = auction.bidding_box();
+    assert_eq!(opening_box.len(), 36);
+    assert_eq!(opening_box[0], (ONE_CLUB, "1♣".to_string()));
+    assert_eq!(opening_box[34], (SEVEN_NOTRUMP, "7NT".to_string()));
+    assert_eq!(opening_box[35], (PASS, "Pass".to_string()));
+
+    auction.bid(ONE_NOTRUMP)?;
+    let bidding_box = auction.bidding_box();
+    let bids: Vec<Bid> = bidding_box.iter().map(|&(bid, _)| bid).collect();
+    assert_eq!(bids.len(), 32);
+    assert_eq!(bidding_box[0], (TWO_CLUBS, "2♣".to_string()));
+    assert!(!bids.contains(&ONE_NOTRUMP));
+    assert!(!bids.contains(&ONE_SPADE));
+    assert!(bids.contains(&PASS));
+    assert!(!bids.contains(&REDOUBLE));
+    assert_eq!(bidding_box.last(), Some(&(DOUBLE, "Dbl".to_string())));
+
+    auction.bid(DOUBLE)?;
+    let labels: Vec<String> = auction
+        .bidding_box()
+        .into_iter()
+        .map(|(_, label)| label)
+        .collect();
+    assert_eq!(labels[labels.len() - 2..], ["Pass", "Rdbl"]);
+
+    Ok(())
+}
+
+#[test]
+fn cue_bids() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid(ONE_HEART)?;
+    auction.bid(ONE_SPADE)?;
+    // South may cue-bid East's spades, but not support North's hearts with one
+    assert!(auction.is_cue_bid(TWO_SPADES));
+    assert!(!auction.is_cue_bid(TWO_HEARTS));
+    assert!(!auction.is_cue_bid(TWO_CLUBS));
+    assert!(!auction.is_cue_bid(ONE_NOTRUMP));
+    assert!(!auction.is_cue_bid(DOUBLE));
+    auction.bid(TWO_SPADES)?;
+
+    // For West, North's hearts are the opponents' suit
+    assert!(auction.is_cue_bid(THREE_HEARTS));
+    assert!(auction.is_cue_bid(THREE_SPADES));
+    assert!(!auction.is_cue_bid(THREE_CLUBS));
+
+    Ok(())
+}
+
+#[test]
+fn current_bidder() -> Result<(), Error> {
+    use BridgeDirection::*;
+
+    let mut auction = Auction::new(W);
+    assert_eq!(auction.current_bidder(), Some(W));
+    for &(bid, next) in [
+        (PASS, N),
+        (ONE_HEART, E),
+        (PASS, S),
+        (TWO_HEARTS, W),
+        (PASS, N),
+    ]
+    .iter()
+    {
+        auction.bid(bid)?;
+        assert_eq!(auction.current_bidder(), Some(next));
+    }
+    auction.bid(PASS)?;
+    assert_eq!(auction.current_bidder(), Some(E));
+    auction.bid(PASS)?;
+    assert_eq!(auction.current_bidder(), None);
+
+    Ok(())
+}
+
+#[test]
+fn bid_by() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::E);
+    assert_eq!(
+        auction.bid_by(BridgeDirection::N, ONE_CLUB),
+        Err(Error::NotYourTurn)
+    );
+    assert!(!auction.has_real_bid());
+    assert_eq!(auction.current_bidder(), Some(BridgeDirection::E));
+
+    auction.bid_by(BridgeDirection::E, ONE_CLUB)?;
+    auction.bid_by(BridgeDirection::S, PASS)?;
+    assert_eq!(
+        auction.bid_by(BridgeDirection::S, ONE_HEART),
+        Err(Error::NotYourTurn)
+    );
+    assert_eq!(
+        auction.bid_by(BridgeDirection::W, ONE_CLUB),
+        Err(Error::InsufficientBid)
+    );
+    auction.bid_by(BridgeDirection::W, ONE_HEART)?;
+    auction.bid_by(BridgeDirection::N, PASS)?;
+    auction.bid_by(BridgeDirection::E, PASS)?;
+    auction.bid_by(BridgeDirection::S, PASS)?;
+    assert_eq!(
+        auction.bid_by(BridgeDirection::W, PASS),
+        Err(Error::NotYourTurn)
+    );
+    assert_eq!(
+        auction.bid_contract().map(|c| c.declarer),
+        Some(BridgeDirection::W)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn history() -> Result<(), Error> {
+    use BridgeDirection::*;
+
+    let mut auction = Auction::new(S);
+    assert!(auction.history().is_empty());
+    auction.bid(PASS)?;
+    auction.bid(ONE_DIAMOND)?;
+    auction.bid(DOUBLE)?;
+    auction.bid(REDOUBLE)?;
+    auction.bid(PASS)?;
+    auction.bid(ONE_SPADE)?;
+    assert_eq!(
+        auction.history(),
+        [
+            (S, PASS),
+            (W, ONE_DIAMOND),
+            (N, DOUBLE),
+            (E, REDOUBLE),
+            (S, PASS),
+            (W, ONE_SPADE),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn alerts() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid_with_alert(ONE_NOTRUMP, None)?;
+    auction.bid_with_alert(TWO_CLUBS, Some("Both majors".to_string()))?;
+    auction.bid(PASS)?;
+    auction.bid_with_alert(TWO_DIAMONDS, Some("Asks for the better major".to_string()))?;
+    assert_eq!(
+        auction.alerts(),
+        [(1, "Both majors"), (3, "Asks for the better major")]
+    );
+
+    assert_eq!(
+        auction.bid_with_alert(ONE_CLUB, Some("Insufficient".to_string())),
+        Err(InsufficientBid)
+    );
+    assert_eq!(auction.alerts().len(), 2);
+
+    assert_eq!(auction.undo_last_bid(), Some(TWO_DIAMONDS));
+    assert_eq!(auction.alerts(), [(1, "Both majors")]);
+
+    auction.bid(TWO_HEARTS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    let contract = auction.bid_contract().expect("The auction is complete");
+    assert_eq!(contract.declarer, BridgeDirection::W);
+    assert_eq!(contract.strain(), Strain::Hearts);
+
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid(ONE_CLUB)?;
+    auction.bid_with_alert(PASS, None)?;
+    assert!(auction.alerts().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn jumps() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    assert!(!auction.is_jump(ONE_SPADE));
+    assert!(auction.is_jump(TWO_SPADES));
+
+    auction.bid(ONE_SPADE)?;
+    assert!(!auction.is_jump(TWO_SPADES));
+    assert!(auction.is_jump(THREE_SPADES));
+    assert!(!auction.is_jump(ONE_NOTRUMP));
+    assert!(auction.is_jump(THREE_CLUBS));
+    assert!(!auction.is_jump(DOUBLE));
+    assert!(!auction.is_jump(PASS));
+
+    auction.bid(SEVEN_HEARTS)?;
+    assert!(!auction.is_jump(SEVEN_NOTRUMP));
+    assert!(!auction.is_jump(SEVEN_CLUBS));
+
+    Ok(())
+}
+
+#[test]
+fn balancing_seat() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert_eq!(auction.consecutive_passes(), 2);
+    assert!(!auction.is_balancing_seat());
+
+    auction.bid(ONE_HEART)?;
+    assert_eq!(auction.consecutive_passes(), 0);
+    assert!(!auction.is_balancing_seat());
+    auction.bid(PASS)?;
+    assert!(!auction.is_balancing_seat());
+    auction.bid(PASS)?;
+    assert_eq!(auction.consecutive_passes(), 2);
+    assert!(auction.is_balancing_seat());
+
+    // Passing out a double of partner's bid isn't balancing
+    auction.bid(DOUBLE)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert!(!auction.is_balancing_seat());
+
+    auction.bid(PASS)?;
+    assert!(auction.is_completed());
+    assert_eq!(auction.consecutive_passes(), 3);
+    assert!(!auction.is_balancing_seat());
+
+    Ok(())
+}
+
+#[test]
+fn default_dealer() -> Result<(), Error> {
+    let mut auction = Auction::default();
+    assert_eq!(auction.current_bidder(), Some(BridgeDirection::N));
+    auction.bid(PASS)?;
+    assert_eq!(auction.current_bidder(), Some(BridgeDirection::E));
+
+    Ok(())
+}
+
+#[test]
+fn undo_last_bid() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::N);
+    assert_eq!(auction.undo_last_bid(), None);
+
+    auction.bid(ONE_HEART)?;
+    auction.bid(ONE_SPADE)?;
+    auction.bid(TWO_HEARTS)?;
+    auction.bid(DOUBLE)?;
+    assert_eq!(auction.undo_last_bid(), Some(DOUBLE));
+    assert_eq!(auction.undo_last_bid(), Some(TWO_HEARTS));
+
+    // South is back on turn, facing East's 1S
+    assert_eq!(auction.bid(DOUBLE), Ok(()));
+    assert_eq!(auction.undo_last_bid(), Some(DOUBLE));
+    assert_eq!(auction.bid(ONE_NOTRUMP), Ok(()));
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+
+    let contract = auction.bid_contract().expect("The auction is complete");
+    assert_eq!(contract.strain(), Strain::NoTrump);
+    assert_eq!(contract.declarer, BridgeDirection::S);
+
+    let mut auction = Auction::new(BridgeDirection::N);
+    auction.bid(ONE_CLUB)?;
+    assert_eq!(auction.undo_last_bid(), Some(ONE_CLUB));
+    assert_eq!(auction.bid(DOUBLE), Err(Error::CantDouble));
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    auction.bid(PASS)?;
+    assert_eq!(auction.contract(), Some(PassedOut));
+
+    Ok(())
+}
+
+#[test]
+fn notation() -> Result<(), Error> {
+    let mut auction = Auction::new(BridgeDirection::E);
+    for &bid in [
+        ONE_CLUB, DOUBLE, REDOUBLE, PASS, PASS, ONE_HEART, PASS, PASS, PASS,
+    ]
+    .iter()
+    {
+        auction.bid(bid)?;
+    }
+    let notation = auction.to_notation();
+    assert_eq!(notation, "1C-X-XX-P;P-1H-P-P;P");
+
+    let parsed = Auction::from_notation(BridgeDirection::E, &notation)?;
+    assert_eq!(parsed.history(), auction.history());
+    assert_eq!(parsed.contract(), auction.contract());
+    assert_eq!(parsed.to_notation(), notation);
+
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "")?.to_notation(),
+        ""
+    );
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "1C-P-1H-P;1NT-P-3NT-P-P-P")?.to_notation(),
+        "1C-P-1H-P;1NT-P-3NT-P;P-P"
+    );
+    Ok(())
+}
+
+#[test]
+fn reject_invalid_notation() {
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "1H-1C").unwrap_err(),
+        InsufficientBid
+    );
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "1H-P-X").unwrap_err(),
+        Error::CantDouble
+    );
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "1H-2Q").unwrap_err(),
+        Error::InvalidNotation(BidParseError::BadStrain)
+    );
+    assert_eq!(
+        Auction::from_notation(BridgeDirection::N, "P-P-P-P;1C").unwrap_err(),
+        Error::AuctionCompleted
+    );
+}
+
+#[test]
+fn error_messages() {
+    fn bid_all(bids: &[Bid]) -> Result<Auction, Box<dyn std::error::Error>> {
+        let mut auction = Auction::new(BridgeDirection::N);
+        for &bid in bids {
+            auction.bid(bid)?;
+        }
+        Ok(auction)
+    }
+
+    assert!(bid_all(&[ONE_CLUB, DOUBLE]).is_ok());
+    let error = bid_all(&[ONE_HEART, ONE_CLUB]).unwrap_err();
+    assert_eq!(error.to_string(), "insufficient bid");
+    assert_eq!(Error::CantDouble.to_string(), "cannot double here");
+    assert_eq!(
+        Error::InvalidNotation(BidParseError::TooShort).to_string(),
+        "invalid auction notation: a bid should be a level followed by a strain, e.g. 1C"
+    );
+
+    let error = Auction::from_notation(BridgeDirection::N, "1H-2Q").unwrap_err();
+    assert_eq!(
+        error.source().map(ToString::to_string),
+        Some(BidParseError::BadStrain.to_string())
+    );
+    assert!(Error::CantDouble.source().is_none());
+}
+
 mod contract {
     use std::convert::{TryFrom, TryInto};
 
@@ -344,7 +877,8 @@ mod contract {
 mod basic {
     use std::convert::TryFrom;
 
-    use crate::auction::StrainBid;
+    use crate::auction::constants::*;
+    use crate::auction::{Bid, Bid::RealBid, BidParseError, BidParseError::*, StrainBid};
     use crate::contract::{ContractLevel, Strain};
 
     #[test]
@@ -361,7 +895,33 @@ mod basic {
     }
 
     #[test]
-    fn read_strain_bid() -> Result<(), &'static str> {
+    fn next_higher() -> Result<(), BidParseError> {
+        let next = |bid: &str| -> Result<Option<String>, BidParseError> {
+            Ok(StrainBid::try_from(bid)?
+                .next_higher()
+                .map(|bid| bid.to_string()))
+        };
+        assert_eq!(next("1c")?.as_deref(), Some("1D"));
+        assert_eq!(next("1s")?.as_deref(), Some("1NT"));
+        assert_eq!(next("1nt")?.as_deref(), Some("2C"));
+        assert_eq!(next("7s")?.as_deref(), Some("7NT"));
+        assert_eq!(next("7nt")?, None);
+
+        let mut bid = StrainBid::try_from("1c")?;
+        let mut count = 1;
+        while let Some(higher) = bid.next_higher() {
+            assert!(higher > bid);
+            bid = higher;
+            count += 1;
+        }
+        assert_eq!(count, 35);
+        assert_eq!(bid, StrainBid::try_from("7nt")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_strain_bid() -> Result<(), BidParseError> {
         assert_eq!(
             StrainBid::try_from("1c")?,
             StrainBid {
@@ -386,4 +946,56 @@ mod basic {
 
         Ok(())
     }
+
+    #[test]
+    fn reject_malformed_strain_bids() {
+        assert_eq!(StrainBid::try_from(""), Err(TooShort));
+        assert_eq!(StrainBid::try_from("4"), Err(TooShort));
+        assert_eq!(StrainBid::try_from("0n"), Err(BadLevel));
+        assert_eq!(StrainBid::try_from("8c"), Err(BadLevel));
+        assert_eq!(StrainBid::try_from("10c"), Err(BadLevel));
+        assert_eq!(StrainBid::try_from("4x"), Err(BadStrain));
+        assert_eq!(StrainBid::try_from("4sx"), Err(TrailingGarbage));
+        assert_eq!(StrainBid::try_from("3ntx"), Err(TrailingGarbage));
+        assert_eq!(StrainBid::try_from("2ct"), Err(TrailingGarbage));
+        assert_eq!(StrainBid::try_from("3nt"), StrainBid::try_from("3N"));
+
+        assert_eq!("".parse::<Bid>(), Err(TooShort));
+        assert_eq!("Q".parse::<Bid>(), Err(BadLevel));
+        assert_eq!(BadLevel.to_string(), "the level should be between 1 and 7");
+        assert_eq!(
+            TrailingGarbage.to_string(),
+            "nothing should follow the strain"
+        );
+    }
+
+    #[test]
+    fn display_strain_bid() -> Result<(), BidParseError> {
+        assert_eq!(StrainBid::try_from("1c")?.to_string(), "1C");
+        assert_eq!(StrainBid::try_from("3n")?.to_string(), "3NT");
+        assert_eq!(StrainBid::try_from("7s")?.to_string(), "7S");
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_and_parse_bids() -> Result<(), BidParseError> {
+        for level in ContractLevel::all() {
+            for strain in Strain::all() {
+                let bid = RealBid(StrainBid { level, strain });
+                assert_eq!(bid.to_string().parse::<Bid>()?, bid);
+                assert_eq!(bid.to_string().to_lowercase().parse::<Bid>()?, bid);
+            }
+        }
+        for &(bid, text) in [(PASS, "P"), (DOUBLE, "X"), (REDOUBLE, "XX")].iter() {
+            assert_eq!(bid.to_string(), text);
+            assert_eq!(text.parse::<Bid>()?, bid);
+            assert_eq!(text.to_lowercase().parse::<Bid>()?, bid);
+        }
+        assert_eq!(ONE_NOTRUMP.to_string(), "1NT");
+        assert!("Q".parse::<Bid>().is_err());
+        assert!("-".parse::<Bid>().is_err());
+
+        Ok(())
+    }
 }
@@ -1,40 +1,588 @@
 use crate::contract::BidContract;
-use crate::Board;
+use crate::evaluation::suit_cards;
+use crate::{seat_order, turns, Board, BridgeDirection, Partnership, SUITS};
+use bridge_deck::{Card, Cards, Suit};
+use std::fmt;
 
 mod trick;
 use trick::CompletedTrick;
 
+/// The play of a board, from the opening lead to the last trick.
+///
+/// It's reached through [`BoardPlay::cardplay`](crate::BoardPlay::cardplay), e.g. to show which cards are legal or who is winning the trick.
 pub struct Cardplay {
     tricks: Vec<CompletedTrick>,
     state: PlayState,
+    hands: [Cards; 4],
+    contract: BidContract,
+    leader: BridgeDirection,
+    current_trick: Vec<(BridgeDirection, Card)>,
+    /// The side who claimed, and how many of the unplayed tricks they claimed
+    claim: Option<(Partnership, usize)>,
+    revoke_policy: RevokePolicy,
+    /// The players who revoked, each with the index of the trick they revoked on
+    revokes: Vec<(BridgeDirection, usize)>,
 }
 
 impl Cardplay {
-    fn start(_board: &Board, _contract: BidContract) -> Self {
+    pub(crate) fn start(board: &Board, contract: BidContract) -> Self {
+        Self::start_with_revoke_policy(board, contract, RevokePolicy::Reject)
+    }
+
+    /// Starts the play, choosing how to deal with a player who doesn't follow suit, see [`RevokePolicy`].
+    pub(crate) fn start_with_revoke_policy(
+        board: &Board,
+        contract: BidContract,
+        revoke_policy: RevokePolicy,
+    ) -> Self {
         Self {
             tricks: vec![],
             state: PlayState::BeforeLead,
+            hands: [board.north, board.east, board.south, board.west],
+            contract,
+            leader: contract.opening_leader(),
+            current_trick: vec![],
+            claim: None,
+            revoke_policy,
+            revokes: vec![],
         }
     }
 
     fn tricks_played(&self) -> usize {
         self.tricks.len()
     }
+
+    /// Returns true once all 13 tricks have been played.
+    pub(crate) fn is_completed(&self) -> bool {
+        self.state == PlayState::Completed
+    }
+
+    /// Returns the player who must play the next card. Declarer plays dummy's cards, but this still reports dummy's seat when it's dummy's turn.
+    pub(crate) fn whose_turn_is_it(&self) -> BridgeDirection {
+        turns(self.leader)
+            .nth(self.current_trick.len())
+            .expect("Turns go on forever")
+    }
+
+    /// Plays a card from a player's hand to the current trick.
+    ///
+    /// Once the fourth card is played, the trick is completed and its winner leads to the next one. After the 13th trick, the play is over.
+    ///
+    /// Returns an error if the play is over, if it's not the player's turn, or if the player doesn't hold the card. A player who holds a card of the suit led must follow suit; with a void in that suit, any card may be played.
+    /// Under [`RevokePolicy::Track`], a card that fails to follow suit is played anyway, and the revoke is recorded.
+    ///
+    /// Once the dummy is tabled, the declarer plays the dummy's cards: on the dummy's turn, either the declarer or the dummy's seat may be given as the player.
+    pub fn play_card(&mut self, player: BridgeDirection, card: Card) -> Result<(), PlayError> {
+        match self.check_play(player, card) {
+            Ok(()) => {}
+            Err(PlayError::MustFollowSuit) if self.revoke_policy == RevokePolicy::Track => self
+                .revokes
+                .push((self.whose_turn_is_it(), self.tricks_played())),
+            Err(error) => return Err(error),
+        }
+
+        let seat = self.whose_turn_is_it();
+        self.hands[seat as usize].remove(card);
+        self.current_trick.push((seat, card));
+        self.state = PlayState::Playing;
+
+        if self.current_trick.len() == 4 {
+            let cards = std::mem::take(&mut self.current_trick)
+                .into_iter()
+                .map(|(_, card)| card)
+                .collect();
+            let trick = CompletedTrick::new(self.leader, cards, self.contract.strain());
+            self.leader = trick.winner();
+            self.tricks.push(trick);
+            if self.tricks_played() == 13 {
+                self.state = PlayState::Completed;
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays a whole trick at once: four cards in clockwise order, starting with `leader`, e.g. when importing a record grouped by tricks.
+    ///
+    /// Every card is checked like in [`play_card`](Cardplay::play_card). The trick is played in full or not at all: if a card is rejected, the cards before it are taken back, and its error is returned.
+    /// Returns [`PlayError::OutOfTurn`] unless `leader` is on lead to a new trick.
+    pub fn play_trick(
+        &mut self,
+        leader: BridgeDirection,
+        cards: [Card; 4],
+    ) -> Result<(), PlayError> {
+        if self.state == PlayState::Completed {
+            return Err(PlayError::PlayCompleted);
+        }
+        if leader != self.leader || !self.current_trick.is_empty() {
+            return Err(PlayError::OutOfTurn);
+        }
+
+        for (played, (who, &card)) in turns(leader).zip(cards.iter()).enumerate() {
+            if let Err(error) = self.play_card(who, card) {
+                for _ in 0..played {
+                    self.undo_last_play();
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes back the last card played, returning it to its player's hand, or returns `None` if no card was played yet.
+    ///
+    /// Taking back the last card of a trick reopens that trick, and the player who led it is on lead again. A revoke recorded for the card is forgotten. If the play ended with a claim, only the claim is withdrawn, and `None` is returned.
+    pub fn undo_last_play(&mut self) -> Option<Card> {
+        if self.claim.take().is_some() {
+            self.state = self.state_after_undo();
+            return None;
+        }
+        if self.current_trick.is_empty() {
+            let trick = self.tricks.pop()?;
+            self.leader = trick.leader();
+            self.current_trick = seat_order(trick.leader())
+                .iter()
+                .map(|&who| (who, trick.card_of(who)))
+                .collect();
+        }
+
+        let (seat, card) = self.current_trick.pop()?;
+        self.hands[seat as usize].insert(card);
+        if self.revokes.last() == Some(&(seat, self.tricks_played())) {
+            self.revokes.pop();
+        }
+        self.state = self.state_after_undo();
+        Some(card)
+    }
+
+    /// Returns the state of the play once a card or a claim has been taken back.
+    fn state_after_undo(&self) -> PlayState {
+        if self.tricks.is_empty() && self.current_trick.is_empty() {
+            PlayState::BeforeLead
+        } else {
+            PlayState::Playing
+        }
+    }
+
+    /// Returns true if the player may play the card right now, i.e. [`play_card`](Cardplay::play_card) would accept it without a revoke.
+    pub fn is_legal_play(&self, who: BridgeDirection, card: Card) -> bool {
+        self.check_play(who, card).is_ok()
+    }
+
+    /// Returns the cards the player may play right now: the whole hand when on lead, otherwise the cards of the suit led, or the whole hand when void in it.
+    ///
+    /// On the dummy's turn, the declarer plays the dummy's cards, so these are the dummy's legal cards. Returns no cards when it's not the player's turn, or once the play is over.
+    pub fn legal_plays(&self, who: BridgeDirection) -> Cards {
+        let seat = match self.seat_played_by(who) {
+            Some(seat) => seat,
+            None => return Cards::EMPTY,
+        };
+        let mut legal = Cards::EMPTY;
+        for card in self.cards_remaining(seat) {
+            if self.is_legal_play(who, card) {
+                legal.insert(card);
+            }
+        }
+        legal
+    }
+
+    /// Returns the dummy, i.e. the declarer's partner, whose hand is tabled once the opening lead is made. Returns `None` before the opening lead.
+    pub fn dummy(&self) -> Option<BridgeDirection> {
+        match self.state {
+            PlayState::BeforeLead => None,
+            _ => Some(self.contract.declarer.partner()),
+        }
+    }
+
+    /// Returns the seat the player would play a card from right now: the player's own seat on their turn, or the dummy's when it's the dummy's turn and the player is the declarer.
+    fn seat_played_by(&self, player: BridgeDirection) -> Option<BridgeDirection> {
+        let on_turn = self.whose_turn_is_it();
+        let controls_dummy = Some(on_turn) == self.dummy() && player == self.contract.declarer;
+        if player == on_turn || controls_dummy {
+            Some(on_turn)
+        } else {
+            None
+        }
+    }
+
+    /// Checks the rules for playing a card, without playing it. Failing to follow suit is always reported, whatever the revoke policy.
+    fn check_play(&self, player: BridgeDirection, card: Card) -> Result<(), PlayError> {
+        if self.state == PlayState::Completed {
+            return Err(PlayError::PlayCompleted);
+        }
+        let seat = self.seat_played_by(player).ok_or(PlayError::OutOfTurn)?;
+        if !self.cards_remaining(seat).contains(card) {
+            return Err(PlayError::CardNotHeld);
+        }
+        if let Some((_, lead)) = self.current_trick.first() {
+            let can_follow = !suit_cards(&self.cards_remaining(seat), lead.suit()).is_empty();
+            if can_follow && card.suit() != lead.suit() {
+                return Err(PlayError::MustFollowSuit);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the cards played so far to the trick in progress, along with the players who played them, starting with the leader.
+    ///
+    /// Once the fourth card is played the trick is completed, so this is empty again right after every completed trick.
+    pub fn current_trick(&self) -> &[(BridgeDirection, Card)] {
+        &self.current_trick
+    }
+
+    /// Returns the player currently winning the trick in progress, along with the winning card, following the same rule as a completed trick: the highest trump, otherwise the highest card of the suit led.
+    ///
+    /// Returns `None` when no card has been led to the current trick yet.
+    pub fn provisional_winner(&self) -> Option<(BridgeDirection, Card)> {
+        let &(leader, _) = self.current_trick.first()?;
+        let cards: Vec<Card> = self.current_trick.iter().map(|&(_, card)| card).collect();
+        Some(CompletedTrick::find_winner(
+            leader,
+            &cards,
+            self.contract.strain(),
+        ))
+    }
+
+    /// Ends the play with a claim: the claimer's side takes `tricks` of the tricks not completed yet, and the opponents take the rest.
+    ///
+    /// The claim is accepted straight away. Returns an error if the play is already over, or if more tricks are claimed than are left to play.
+    pub fn claim(&mut self, claimer: BridgeDirection, tricks: usize) -> Result<(), PlayError> {
+        if self.state == PlayState::Completed {
+            return Err(PlayError::PlayCompleted);
+        }
+        if tricks > 13 - self.tricks_played() {
+            return Err(PlayError::TooManyTricksClaimed);
+        }
+
+        self.claim = Some((Partnership::of(claimer), tricks));
+        self.state = PlayState::Completed;
+        Ok(())
+    }
+
+    /// Returns the opening lead, i.e. the first card played to the first trick, or `None` if play hasn't started yet.
+    pub fn opening_lead(&self) -> Option<Card> {
+        match self.tricks.first() {
+            Some(trick) => Some(trick.card_of(trick.leader())),
+            None => self.current_trick.first().map(|&(_, card)| card),
+        }
+    }
+
+    /// Returns the number of completed tricks won by the declaring side, including any tricks conceded to it by a claim.
+    ///
+    /// Once the play is over, the tricks transferred for revokes are taken into account, see [`RevokePolicy::Track`].
+    pub fn tricks_for_declarer(&self) -> usize {
+        self.tricks_won_by(Partnership::of(self.contract.declarer))
+    }
+
+    /// Returns the number of completed tricks won by the defenders, including any tricks conceded to them by a claim.
+    pub fn tricks_for_defense(&self) -> usize {
+        self.tricks_won_by(Partnership::of(self.contract.declarer).opponents())
+    }
+
+    /// Returns the defenders' progress towards setting the contract: the tricks they have won, and the tricks they need in total to defeat it.
+    ///
+    /// Defeating a contract takes one trick more than declarer can afford to lose, e.g. 4 tricks against `4S`. Returns `None` before the opening lead, like [`dummy`](Cardplay::dummy).
+    pub fn defense_progress(&self) -> Option<(usize, usize)> {
+        match self.state {
+            PlayState::BeforeLead => None,
+            _ => Some((
+                self.tricks_for_defense(),
+                14 - self.contract.tricks_needed(),
+            )),
+        }
+    }
+
+    fn tricks_won_by(&self, side: Partnership) -> usize {
+        let won = self
+            .tricks
+            .iter()
+            .filter(|trick| side.contains(trick.winner()))
+            .count();
+        let claimed = match self.claim {
+            None => 0,
+            Some((claimer, tricks)) if claimer == side => tricks,
+            Some((_, tricks)) => 13 - self.tricks_played() - tricks,
+        };
+        if self.state != PlayState::Completed {
+            return won + claimed;
+        }
+
+        won + claimed + self.tricks_transferred(side.opponents()) - self.tricks_transferred(side)
+    }
+
+    /// Returns the number of tricks a side must transfer to the opponents for all its revokes.
+    ///
+    /// A side can't transfer more tricks than it won from its first revoke trick on, however many times it revoked.
+    fn tricks_transferred(&self, side: Partnership) -> usize {
+        let revokes = self
+            .revokes
+            .iter()
+            .filter(|(offender, _)| side.contains(*offender));
+        let first_revoke = match revokes.clone().map(|&(_, trick)| trick).min() {
+            Some(trick) => trick,
+            None => return 0,
+        };
+        let penalty: usize = revokes.map(|&revoke| self.revoke_penalty(revoke)).sum();
+        let won_since = self
+            .tricks
+            .iter()
+            .skip(first_revoke)
+            .filter(|trick| side.contains(trick.winner()))
+            .count();
+        penalty.min(won_since)
+    }
+
+    /// Returns the number of tricks the revoking side must transfer to the opponents for a revoke.
+    ///
+    /// If the revoking player won the revoke trick, that trick is transferred, plus one more if their side won any later trick. Otherwise, one trick is transferred if their side won the revoke trick or any later one. Tricks not played because of a claim aren't counted.
+    fn revoke_penalty(&self, (offender, trick): (BridgeDirection, usize)) -> usize {
+        let side = Partnership::of(offender);
+        let revoke_trick = match self.tricks.get(trick) {
+            Some(revoke_trick) => revoke_trick,
+            None => return 0,
+        };
+        let won_later = self.tricks[trick + 1..]
+            .iter()
+            .any(|later| side.contains(later.winner()));
+
+        if revoke_trick.winner() == offender {
+            1 + won_later as usize
+        } else if side.contains(revoke_trick.winner()) || won_later {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Returns how the contract stands: the tricks left to play, the tricks declarer still needs, and whether the outcome is already settled.
+    ///
+    /// Returns `None` before the first trick is completed.
+    pub fn endgame_status(&self) -> Option<EndgameStatus> {
+        if self.tricks.is_empty() {
+            return None;
+        }
+
+        let tricks_remaining = match self.claim {
+            Some(_) => 0,
+            None => 13 - self.tricks_played(),
+        };
+        let tricks_needed = self
+            .contract
+            .tricks_needed()
+            .saturating_sub(self.tricks_for_declarer());
+        let fate = if tricks_needed == 0 {
+            ContractFate::Made
+        } else if tricks_needed > tricks_remaining {
+            ContractFate::Defeated
+        } else {
+            ContractFate::Open
+        };
+
+        Some(EndgameStatus {
+            tricks_remaining,
+            tricks_needed,
+            fate,
+        })
+    }
+
+    /// Returns the cards a player still holds, i.e. the player's hand minus the cards already played.
+    pub fn cards_remaining(&self, who: BridgeDirection) -> Cards {
+        self.hands[who as usize]
+    }
+
+    /// Returns a side's cards in a suit that rank above all of the opponents' cards in that suit.
+    ///
+    /// If the opponents hold no cards in the suit, all of the side's cards in it are returned.
+    fn top_cards(&self, side: Partnership, suit: Suit) -> Cards {
+        let (ours, theirs): (Vec<BridgeDirection>, Vec<BridgeDirection>) =
+            BridgeDirection::iter().partition(|&who| side.contains(who));
+        let in_suit = |who: &BridgeDirection| suit_cards(&self.cards_remaining(*who), suit);
+
+        let best_opposing = theirs
+            .iter()
+            .flat_map(in_suit)
+            .map(|card| card.rank())
+            .max()
+            .unwrap_or(0);
+        let mut top = Cards::EMPTY;
+        for card in ours.iter().flat_map(in_suit) {
+            if card.rank() > best_opposing {
+                top.insert(card);
+            }
+        }
+        top
+    }
+
+    /// Counts the tricks a side can cash straight away, from the top of each suit.
+    ///
+    /// In every suit, the side's cards ranking above all of the opponents' cards are winners, up to the length of the side's longer holding. If the opponents hold no cards in a suit, every card of the longer holding is a winner.
+    /// This is only a quick estimate: it doesn't account for entries, blockages or trumps.
+    pub fn sure_tricks(&self, side: Partnership) -> usize {
+        SUITS
+            .iter()
+            .map(|&suit| {
+                let longer = BridgeDirection::iter()
+                    .filter(|&who| side.contains(who))
+                    .map(|who| suit_cards(&self.cards_remaining(who), suit).len())
+                    .max()
+                    .unwrap_or(0);
+                self.top_cards(side, suit).len().min(longer)
+            })
+            .sum()
+    }
+
+    /// Estimates how many times a player's hand can be reached, by counting its sure winners outside the trump suit.
+    ///
+    /// A card counts as an entry when it ranks above every card the opponents hold in its suit. This is a heuristic: it ignores ruffs, blockages, and the entries that could be established by giving up a trick.
+    pub fn entries(&self, dir: BridgeDirection) -> usize {
+        let trump = self.contract.strain().trump_suit();
+        SUITS
+            .iter()
+            .filter(|&&suit| Some(suit) != trump)
+            .map(|&suit| {
+                self.top_cards(Partnership::of(dir), suit)
+                    .intersection(self.cards_remaining(dir))
+                    .len()
+            })
+            .sum()
+    }
+}
+
+/// A summary of how the contract stands during the play, see [`Cardplay::endgame_status`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct EndgameStatus {
+    /// The number of tricks still to be played
+    pub tricks_remaining: usize,
+    /// The number of tricks declarer still needs to make the contract
+    pub tricks_needed: usize,
+    /// Whether the outcome of the contract is already settled
+    pub fate: ContractFate,
+}
+
+/// Whether the outcome of a contract is settled before the end of play.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum ContractFate {
+    /// Declarer has already taken enough tricks to make the contract
+    Made,
+    /// Declarer can no longer make the contract, even by taking all the remaining tricks
+    Defeated,
+    /// The contract may still go either way
+    Open,
+}
+
+/// How the play deals with a revoke, i.e. a player who doesn't follow suit while holding a card of the suit led.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum RevokePolicy {
+    /// The card is refused with [`PlayError::MustFollowSuit`]
+    Reject,
+
+    /// The card is played and the revoke is recorded. When the play is over, the revoke is established and tricks are transferred to the opponents, as the laws require
+    Track,
 }
 
 #[derive(Eq, PartialEq, Debug)]
 enum PlayState {
+    /// The opening lead hasn't been made yet
     BeforeLead,
+    /// At least one card has been played, and there are tricks left to play
+    Playing,
+    /// All 13 tricks have been played, or the rest of them were claimed
+    Completed,
 }
 
+/// These are possible errors arising from playing a card.
+#[derive(Debug, Eq, PartialEq)]
+pub enum PlayError {
+    /// All 13 tricks have already been played
+    PlayCompleted,
+
+    /// Another player must play the next card
+    OutOfTurn,
+
+    /// The player doesn't hold the card, e.g. because it was already played
+    CardNotHeld,
+
+    /// The player holds a card of the suit led, and must play one
+    MustFollowSuit,
+
+    /// A claim was made for more tricks than are left to play
+    TooManyTricksClaimed,
+}
+
+impl fmt::Display for PlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PlayError::PlayCompleted => "all tricks have been played",
+            PlayError::OutOfTurn => "not your turn to play",
+            PlayError::CardNotHeld => "card not held",
+            PlayError::MustFollowSuit => "must follow suit",
+            PlayError::TooManyTricksClaimed => "more tricks claimed than are left to play",
+        })
+    }
+}
+
+impl std::error::Error for PlayError {}
+
 #[cfg(test)]
 mod tests {
     use crate::auction::StrainBid;
-    use crate::cardplay::{Cardplay, PlayState};
-    use crate::contract::{BidContract, Modifier};
-    use crate::{Board, BridgeDirection};
+    use crate::cardplay::{
+        trick::CompletedTrick, Cardplay, ContractFate, EndgameStatus, PlayError, PlayState,
+        RevokePolicy,
+    };
+    use crate::contract::{BidContract, Modifier, Strain};
+    use crate::evaluation::suit_cards;
+    use crate::tests::suited_board;
+    use crate::{Board, BridgeDirection, Partnership};
+    use bridge_deck::{Card, Cards};
     use std::convert::TryFrom;
 
+    fn hand(cards: &[Card]) -> Cards {
+        let mut hand = Cards::EMPTY;
+        for &card in cards {
+            hand.insert(card);
+        }
+        hand
+    }
+
+    /// A full deal where North holds the two red aces, with South playing in spades.
+    #[rustfmt::skip]
+    fn spade_game() -> Board {
+        Board {
+            north: hand(&[
+                Card::SA, Card::S7, Card::S6, Card::S5,
+                Card::HA, Card::H4, Card::H3,
+                Card::DA, Card::D5, Card::D4,
+                Card::C4, Card::C3, Card::C2,
+            ]),
+            east: hand(&[
+                Card::S8, Card::S4, Card::S3, Card::S2,
+                Card::HK, Card::HQ, Card::HJ,
+                Card::DK, Card::DQ, Card::DJ,
+                Card::CA, Card::CK, Card::CQ,
+            ]),
+            south: hand(&[
+                Card::SK, Card::SQ, Card::SJ, Card::ST, Card::S9,
+                Card::H7, Card::H6, Card::H5,
+                Card::D8, Card::D7, Card::D6,
+                Card::C6, Card::C5,
+            ]),
+            west: hand(&[
+                Card::HT, Card::H9, Card::H8, Card::H2,
+                Card::DT, Card::D9, Card::D3, Card::D2,
+                Card::CJ, Card::CT, Card::C9, Card::C8, Card::C7,
+            ]),
+            number: 1,
+        }
+    }
+
+    fn four_spades_by_south() -> BidContract {
+        BidContract {
+            contract: StrainBid::try_from("4s").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        }
+    }
+
     #[test]
     fn start_new_board() -> Result<(), ()> {
         let board = Board::new(3);
@@ -49,4 +597,642 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn play_card() {
+        let board = suited_board();
+        let contract = BidContract {
+            contract: StrainBid::try_from("1n").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        let mut play = Cardplay::start(&board, contract);
+
+        assert_eq!(
+            play.play_card(BridgeDirection::N, Card::SA),
+            Err(PlayError::OutOfTurn)
+        );
+        assert_eq!(
+            play.play_card(BridgeDirection::W, Card::SA),
+            Err(PlayError::CardNotHeld)
+        );
+        assert_eq!(play.state, PlayState::BeforeLead);
+
+        play.play_card(BridgeDirection::W, Card::C2).unwrap();
+        assert_eq!(play.state, PlayState::Playing);
+        assert_eq!(play.opening_lead(), Some(Card::C2));
+        play.play_card(BridgeDirection::N, Card::SA).unwrap();
+        play.play_card(BridgeDirection::E, Card::HA).unwrap();
+        play.play_card(BridgeDirection::S, Card::DA).unwrap();
+        assert_eq!(play.tricks_played(), 1);
+        assert_eq!(play.leader, BridgeDirection::W);
+        assert_eq!(
+            play.play_card(BridgeDirection::W, Card::C2),
+            Err(PlayError::CardNotHeld)
+        );
+
+        // West takes every trick, while the others throw their cards away
+        for _ in 1..13 {
+            for &who in &[
+                BridgeDirection::W,
+                BridgeDirection::N,
+                BridgeDirection::E,
+                BridgeDirection::S,
+            ] {
+                let card = play.cards_remaining(who).next().unwrap();
+                play.play_card(who, card).unwrap();
+            }
+        }
+        assert_eq!(play.tricks_played(), 13);
+        assert_eq!(play.state, PlayState::Completed);
+        assert_eq!(
+            play.play_card(BridgeDirection::W, Card::CA),
+            Err(PlayError::PlayCompleted)
+        );
+    }
+
+    #[test]
+    fn follow_suit() {
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        assert_eq!(
+            play.play_card(BridgeDirection::N, Card::DA),
+            Err(PlayError::MustFollowSuit)
+        );
+        play.play_card(BridgeDirection::N, Card::H3).unwrap();
+        play.play_card(BridgeDirection::E, Card::HK).unwrap();
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+        assert_eq!(play.tricks_for_declarer(), 0);
+        assert_eq!(play.tricks_for_defense(), 1);
+
+        // South may not ruff while holding clubs
+        play.play_card(BridgeDirection::E, Card::CA).unwrap();
+        assert_eq!(
+            play.play_card(BridgeDirection::S, Card::SK),
+            Err(PlayError::MustFollowSuit)
+        );
+        play.play_card(BridgeDirection::S, Card::C5).unwrap();
+        play.play_card(BridgeDirection::W, Card::C7).unwrap();
+        play.play_card(BridgeDirection::N, Card::C2).unwrap();
+
+        // West has no spades, so any card will do
+        play.play_card(BridgeDirection::E, Card::S2).unwrap();
+        play.play_card(BridgeDirection::S, Card::S9).unwrap();
+        assert_eq!(play.play_card(BridgeDirection::W, Card::D2), Ok(()));
+        assert_eq!(play.tricks_played(), 2);
+    }
+
+    #[test]
+    fn tricks_per_side() {
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+        assert_eq!(play.tricks_for_declarer(), 0);
+        assert_eq!(play.tricks_for_defense(), 0);
+
+        // North's red aces and the ace of trumps
+        for &(who, card) in &[
+            (BridgeDirection::W, Card::H2),
+            (BridgeDirection::N, Card::HA),
+            (BridgeDirection::E, Card::HJ),
+            (BridgeDirection::S, Card::H5),
+            (BridgeDirection::N, Card::DA),
+            (BridgeDirection::E, Card::DJ),
+            (BridgeDirection::S, Card::D6),
+            (BridgeDirection::W, Card::D2),
+            (BridgeDirection::N, Card::SA),
+            (BridgeDirection::E, Card::S2),
+            (BridgeDirection::S, Card::S9),
+            (BridgeDirection::W, Card::C7),
+        ] {
+            play.play_card(who, card).unwrap();
+        }
+        assert_eq!(play.tricks_for_declarer(), 3);
+        assert_eq!(play.tricks_for_defense(), 0);
+
+        for &(who, card) in &[
+            (BridgeDirection::N, Card::C2),
+            (BridgeDirection::E, Card::CA),
+            (BridgeDirection::S, Card::C5),
+            (BridgeDirection::W, Card::C8),
+        ] {
+            play.play_card(who, card).unwrap();
+        }
+        assert_eq!(play.tricks_for_declarer(), 3);
+        assert_eq!(play.tricks_for_defense(), 1);
+        assert_eq!(play.defense_progress(), Some((1, 4)));
+    }
+
+    #[test]
+    fn defense_progress() {
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+        assert_eq!(play.defense_progress(), None);
+
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        assert_eq!(play.defense_progress(), Some((0, 4)));
+        play.play_card(BridgeDirection::N, Card::H3).unwrap();
+        play.play_card(BridgeDirection::E, Card::HK).unwrap();
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+        assert_eq!(play.defense_progress(), Some((1, 4)));
+
+        let slam = BidContract {
+            contract: StrainBid::try_from("7n").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        let mut play = Cardplay::start(&spade_game(), slam);
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        assert_eq!(play.defense_progress(), Some((0, 1)));
+    }
+
+    #[test]
+    fn sure_tricks() {
+        #[rustfmt::skip]
+        let board = Board {
+            north: hand(&[
+                Card::SA, Card::SK, Card::SQ, Card::SJ, Card::ST,
+                Card::HA, Card::H2,
+                Card::D5, Card::D4, Card::D3, Card::D2,
+                Card::C3, Card::C2,
+            ]),
+            east: hand(&[
+                Card::S9, Card::S8, Card::S7, Card::S6, Card::S5, Card::S4,
+                Card::HQ, Card::HJ, Card::HT, Card::H9, Card::H8, Card::H7, Card::H6,
+            ]),
+            south: hand(&[
+                Card::S3, Card::S2,
+                Card::HK, Card::H4, Card::H3,
+                Card::DA, Card::D7, Card::D6,
+                Card::C8, Card::C7, Card::C6, Card::C5, Card::C4,
+            ]),
+            west: hand(&[
+                Card::H5,
+                Card::DK, Card::DQ, Card::DJ, Card::DT, Card::D9, Card::D8,
+                Card::CA, Card::CK, Card::CQ, Card::CJ, Card::CT, Card::C9,
+            ]),
+            number: 1,
+        };
+        let contract = BidContract {
+            contract: StrainBid::try_from("3n").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        let play = Cardplay::start(&board, contract);
+
+        // Five running spades, the two top hearts and the ace of diamonds
+        assert_eq!(play.sure_tricks(Partnership::NS), 8);
+        // Six running clubs
+        assert_eq!(play.sure_tricks(Partnership::EW), 6);
+    }
+
+    #[test]
+    fn opening_lead() {
+        let board = Board::new(1);
+        let contract = BidContract {
+            contract: StrainBid::try_from("4s").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        let mut play = Cardplay::start(&board, contract);
+        assert_eq!(play.opening_lead(), None);
+
+        play.tricks.push(CompletedTrick::new(
+            BridgeDirection::W,
+            vec![Card::HK, Card::H2, Card::HA, Card::H5],
+            Strain::Spades,
+        ));
+        assert_eq!(play.opening_lead(), Some(Card::HK));
+    }
+
+    #[test]
+    fn entries() {
+        let play = Cardplay::start(&spade_game(), four_spades_by_south());
+
+        // The two red aces, but not the ace of trumps
+        assert_eq!(play.entries(BridgeDirection::N), 2);
+        assert_eq!(play.entries(BridgeDirection::S), 0);
+    }
+
+    #[test]
+    fn endgame_status() {
+        let board = Board::new(1);
+        let contract = BidContract {
+            contract: StrainBid::try_from("4s").unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        let trick_won_by = |winner| {
+            CompletedTrick::new(
+                winner,
+                vec![Card::SA, Card::S2, Card::S3, Card::S4],
+                Strain::Spades,
+            )
+        };
+
+        let mut play = Cardplay::start(&board, contract);
+        assert_eq!(play.endgame_status(), None);
+
+        play.tricks.push(trick_won_by(BridgeDirection::E));
+        assert_eq!(
+            play.endgame_status(),
+            Some(EndgameStatus {
+                tricks_remaining: 12,
+                tricks_needed: 10,
+                fate: ContractFate::Open,
+            })
+        );
+
+        for _ in 0..10 {
+            play.tricks.push(trick_won_by(BridgeDirection::N));
+        }
+        assert_eq!(
+            play.endgame_status(),
+            Some(EndgameStatus {
+                tricks_remaining: 2,
+                tricks_needed: 0,
+                fate: ContractFate::Made,
+            })
+        );
+
+        let mut play = Cardplay::start(&board, contract);
+        for _ in 0..4 {
+            play.tricks.push(trick_won_by(BridgeDirection::W));
+        }
+        assert_eq!(play.endgame_status().unwrap().fate, ContractFate::Defeated);
+    }
+
+    #[test]
+    fn claim() {
+        let board = Board::new(1);
+        let trick_won_by = |winner| {
+            CompletedTrick::new(
+                winner,
+                vec![Card::SA, Card::S2, Card::S3, Card::S4],
+                Strain::Spades,
+            )
+        };
+        let nine_tricks_played = || {
+            let mut play = Cardplay::start(&board, four_spades_by_south());
+            for _ in 0..6 {
+                play.tricks.push(trick_won_by(BridgeDirection::N));
+            }
+            for _ in 0..3 {
+                play.tricks.push(trick_won_by(BridgeDirection::E));
+            }
+            play.state = PlayState::Playing;
+            play
+        };
+
+        let mut play = nine_tricks_played();
+        assert_eq!(
+            play.claim(BridgeDirection::S, 5),
+            Err(PlayError::TooManyTricksClaimed)
+        );
+        assert_eq!(play.claim(BridgeDirection::S, 4), Ok(()));
+        assert!(play.is_completed());
+        assert_eq!(play.tricks_for_declarer(), 10);
+        assert_eq!(play.tricks_for_defense(), 3);
+        assert_eq!(play.endgame_status().unwrap().tricks_remaining, 0);
+        assert_eq!(play.endgame_status().unwrap().fate, ContractFate::Made);
+        assert_eq!(
+            play.claim(BridgeDirection::S, 0),
+            Err(PlayError::PlayCompleted)
+        );
+        assert_eq!(
+            play.play_card(BridgeDirection::N, Card::SA),
+            Err(PlayError::PlayCompleted)
+        );
+
+        // A defender claiming one of the last four concedes the other three
+        let mut play = nine_tricks_played();
+        assert_eq!(play.claim(BridgeDirection::W, 1), Ok(()));
+        assert_eq!(play.tricks_for_declarer(), 9);
+        assert_eq!(play.tricks_for_defense(), 4);
+    }
+
+    #[test]
+    fn current_trick_and_cards_remaining() {
+        let board = spade_game();
+        let mut play = Cardplay::start(&board, four_spades_by_south());
+        assert!(play.current_trick().is_empty());
+        assert_eq!(play.cards_remaining(BridgeDirection::N), board.north);
+
+        play.play_card(BridgeDirection::W, Card::H2).unwrap();
+        play.play_card(BridgeDirection::N, Card::HA).unwrap();
+        assert_eq!(
+            play.current_trick(),
+            [
+                (BridgeDirection::W, Card::H2),
+                (BridgeDirection::N, Card::HA)
+            ]
+        );
+        assert_eq!(play.cards_remaining(BridgeDirection::N).len(), 12);
+        assert!(!play.cards_remaining(BridgeDirection::N).contains(Card::HA));
+        assert_eq!(play.cards_remaining(BridgeDirection::E), board.east);
+
+        play.play_card(BridgeDirection::E, Card::HJ).unwrap();
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+        assert!(play.current_trick().is_empty());
+        for who in BridgeDirection::iter() {
+            assert_eq!(play.cards_remaining(who).len(), 12);
+        }
+    }
+
+    #[test]
+    fn established_revoke() {
+        let mut play = Cardplay::start_with_revoke_policy(
+            &spade_game(),
+            four_spades_by_south(),
+            RevokePolicy::Track,
+        );
+
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        play.play_card(BridgeDirection::N, Card::H3).unwrap();
+        play.play_card(BridgeDirection::E, Card::HK).unwrap();
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+
+        // South discards a diamond while holding clubs, and East wins the trick
+        play.play_card(BridgeDirection::E, Card::CA).unwrap();
+        assert_eq!(play.play_card(BridgeDirection::S, Card::D6), Ok(()));
+        play.play_card(BridgeDirection::W, Card::C7).unwrap();
+        play.play_card(BridgeDirection::N, Card::C2).unwrap();
+
+        // Everyone follows suit from now on, playing their highest card
+        while !play.is_completed() {
+            let who = play.whose_turn_is_it();
+            let hand = play.cards_remaining(who);
+            let mut cards = match play.current_trick().first() {
+                Some((_, lead)) if !suit_cards(&hand, lead.suit()).is_empty() => {
+                    suit_cards(&hand, lead.suit())
+                }
+                _ => hand,
+            };
+            play.play_card(who, cards.next().unwrap()).unwrap();
+        }
+
+        // North-South win later tricks, so the revoke costs them exactly one
+        let won_by_ns = play
+            .tricks
+            .iter()
+            .filter(|trick| Partnership::NS.contains(trick.winner()))
+            .count();
+        assert!(won_by_ns > 0);
+        assert_eq!(play.revokes, [(BridgeDirection::S, 1)]);
+        assert_eq!(play.tricks_for_declarer(), won_by_ns - 1);
+        assert_eq!(play.tricks_for_defense(), 13 - won_by_ns + 1);
+
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+        play.play_card(BridgeDirection::W, Card::C7).unwrap();
+        play.play_card(BridgeDirection::N, Card::C2).unwrap();
+        play.play_card(BridgeDirection::E, Card::CA).unwrap();
+        assert_eq!(
+            play.play_card(BridgeDirection::S, Card::D6),
+            Err(PlayError::MustFollowSuit)
+        );
+    }
+
+    #[test]
+    fn repeated_revokes() {
+        let mut play = Cardplay::start_with_revoke_policy(
+            &spade_game(),
+            four_spades_by_south(),
+            RevokePolicy::Track,
+        );
+        play.play_trick(BridgeDirection::W, [Card::HT, Card::H3, Card::HK, Card::H5])
+            .unwrap();
+
+        // South discards diamonds on two club tricks won by East
+        play.play_trick(BridgeDirection::E, [Card::CA, Card::D6, Card::C7, Card::C2])
+            .unwrap();
+        play.play_trick(BridgeDirection::E, [Card::CK, Card::D7, Card::C8, Card::C3])
+            .unwrap();
+        play.play_trick(BridgeDirection::E, [Card::HQ, Card::H6, Card::H2, Card::HA])
+            .unwrap();
+        assert_eq!(
+            play.revokes,
+            [(BridgeDirection::S, 1), (BridgeDirection::S, 2)]
+        );
+
+        // Each revoke costs a trick, but North-South only have one to give
+        play.claim(BridgeDirection::N, 0).unwrap();
+        assert_eq!(play.tricks_for_declarer(), 0);
+        assert_eq!(play.tricks_for_defense(), 13);
+    }
+
+    #[test]
+    fn play_trick() {
+        let board = spade_game();
+        let mut play = Cardplay::start(&board, four_spades_by_south());
+        assert_eq!(
+            play.play_trick(BridgeDirection::N, [Card::HA, Card::HK, Card::H5, Card::H2]),
+            Err(PlayError::OutOfTurn)
+        );
+
+        let first_trick = [Card::HT, Card::H3, Card::HK, Card::H5];
+        assert_eq!(play.play_trick(BridgeDirection::W, first_trick), Ok(()));
+        assert_eq!(play.tricks_played(), 1);
+        assert_eq!(play.tricks_for_defense(), 1);
+
+        // South discards while holding clubs: the whole trick is refused
+        assert_eq!(
+            play.play_trick(BridgeDirection::E, [Card::CA, Card::D6, Card::C7, Card::C2]),
+            Err(PlayError::MustFollowSuit)
+        );
+        assert_eq!(play.tricks_played(), 1);
+        assert!(play.current_trick().is_empty());
+        assert!(play.cards_remaining(BridgeDirection::E).contains(Card::CA));
+        assert_eq!(play.whose_turn_is_it(), BridgeDirection::E);
+
+        assert_eq!(
+            play.play_trick(BridgeDirection::E, [Card::CA, Card::C5, Card::C7, Card::C2]),
+            Ok(())
+        );
+        assert_eq!(play.tricks_played(), 2);
+    }
+
+    #[test]
+    fn undo_last_play() {
+        let board = spade_game();
+        let mut play =
+            Cardplay::start_with_revoke_policy(&board, four_spades_by_south(), RevokePolicy::Track);
+        assert_eq!(play.undo_last_play(), None);
+
+        play.play_card(BridgeDirection::W, Card::H2).unwrap();
+        assert_eq!(play.undo_last_play(), Some(Card::H2));
+        assert_eq!(play.state, PlayState::BeforeLead);
+        assert_eq!(play.dummy(), None);
+        assert_eq!(play.cards_remaining(BridgeDirection::W), board.west);
+
+        // Taking back the card that completed a trick reopens it, with its leader on lead again
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        play.play_card(BridgeDirection::N, Card::H3).unwrap();
+        play.play_card(BridgeDirection::E, Card::HK).unwrap();
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+        assert_eq!(play.undo_last_play(), Some(Card::H5));
+        assert_eq!(play.tricks_played(), 0);
+        assert_eq!(play.leader, BridgeDirection::W);
+        assert_eq!(play.whose_turn_is_it(), BridgeDirection::S);
+        assert_eq!(play.current_trick().len(), 3);
+        play.play_card(BridgeDirection::S, Card::H6).unwrap();
+        assert_eq!(play.tricks_played(), 1);
+
+        // A revoke is forgotten along with its card
+        play.play_card(BridgeDirection::E, Card::CA).unwrap();
+        play.play_card(BridgeDirection::S, Card::D6).unwrap();
+        assert_eq!(play.revokes, [(BridgeDirection::S, 1)]);
+        assert_eq!(play.undo_last_play(), Some(Card::D6));
+        assert!(play.revokes.is_empty());
+
+        // A claim is withdrawn on its own
+        play.claim(BridgeDirection::S, 10).unwrap();
+        assert_eq!(play.undo_last_play(), None);
+        assert!(!play.is_completed());
+        assert_eq!(play.tricks_for_declarer(), 0);
+        assert_eq!(play.current_trick(), [(BridgeDirection::E, Card::CA)]);
+    }
+
+    #[test]
+    fn provisional_winner() {
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+        assert_eq!(play.provisional_winner(), None);
+
+        play.play_card(BridgeDirection::W, Card::H2).unwrap();
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::W, Card::H2))
+        );
+        play.play_card(BridgeDirection::N, Card::H3).unwrap();
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::N, Card::H3))
+        );
+        play.play_card(BridgeDirection::E, Card::HK).unwrap();
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::E, Card::HK))
+        );
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+        assert_eq!(play.provisional_winner(), None);
+
+        // East cashes two clubs, then South ruffs the third one
+        for &(who, card) in &[
+            (BridgeDirection::E, Card::CA),
+            (BridgeDirection::S, Card::C5),
+            (BridgeDirection::W, Card::C7),
+            (BridgeDirection::N, Card::C2),
+            (BridgeDirection::E, Card::CK),
+            (BridgeDirection::S, Card::C6),
+            (BridgeDirection::W, Card::C8),
+            (BridgeDirection::N, Card::C3),
+            (BridgeDirection::E, Card::CQ),
+        ] {
+            play.play_card(who, card).unwrap();
+        }
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::E, Card::CQ))
+        );
+        play.play_card(BridgeDirection::S, Card::S9).unwrap();
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::S, Card::S9))
+        );
+        play.play_card(BridgeDirection::W, Card::C9).unwrap();
+        assert_eq!(
+            play.provisional_winner(),
+            Some((BridgeDirection::S, Card::S9))
+        );
+    }
+
+    #[test]
+    fn is_legal_play() {
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+
+        // Any card may be led, but only by the player on lead
+        assert!(play.is_legal_play(BridgeDirection::W, Card::HT));
+        assert!(play.is_legal_play(BridgeDirection::W, Card::CJ));
+        assert!(!play.is_legal_play(BridgeDirection::W, Card::SA));
+        assert!(!play.is_legal_play(BridgeDirection::N, Card::SA));
+
+        play.play_card(BridgeDirection::W, Card::HT).unwrap();
+        assert!(play.is_legal_play(BridgeDirection::N, Card::H3));
+        assert!(play.is_legal_play(BridgeDirection::N, Card::HA));
+        assert!(!play.is_legal_play(BridgeDirection::N, Card::DA));
+        assert!(!play.is_legal_play(BridgeDirection::N, Card::HT));
+        assert!(!play.is_legal_play(BridgeDirection::E, Card::HK));
+
+        let mut tracking = Cardplay::start_with_revoke_policy(
+            &spade_game(),
+            four_spades_by_south(),
+            RevokePolicy::Track,
+        );
+        tracking.play_card(BridgeDirection::W, Card::HT).unwrap();
+        assert!(!tracking.is_legal_play(BridgeDirection::N, Card::DA));
+    }
+
+    #[test]
+    fn legal_plays() {
+        let board = spade_game();
+        let mut play = Cardplay::start(&board, four_spades_by_south());
+        assert_eq!(play.legal_plays(BridgeDirection::W), board.west);
+        assert!(play.legal_plays(BridgeDirection::N).is_empty());
+
+        play.play_card(BridgeDirection::W, Card::DT).unwrap();
+        assert_eq!(
+            play.legal_plays(BridgeDirection::N),
+            hand(&[Card::DA, Card::D5, Card::D4])
+        );
+        play.play_card(BridgeDirection::N, Card::DA).unwrap();
+        play.play_card(BridgeDirection::E, Card::DJ).unwrap();
+        play.play_card(BridgeDirection::S, Card::D6).unwrap();
+
+        // West is void in spades, so any card will do
+        play.play_card(BridgeDirection::N, Card::S5).unwrap();
+        play.play_card(BridgeDirection::E, Card::S8).unwrap();
+        play.play_card(BridgeDirection::S, Card::SK).unwrap();
+        assert_eq!(
+            play.legal_plays(BridgeDirection::W),
+            play.cards_remaining(BridgeDirection::W)
+        );
+        assert_eq!(play.legal_plays(BridgeDirection::W).len(), 12);
+    }
+
+    #[test]
+    fn dummy() {
+        // South declares, so North is the dummy
+        let mut play = Cardplay::start(&spade_game(), four_spades_by_south());
+        assert_eq!(play.dummy(), None);
+        assert_eq!(
+            play.play_card(BridgeDirection::S, Card::HA),
+            Err(PlayError::OutOfTurn)
+        );
+
+        play.play_card(BridgeDirection::W, Card::H2).unwrap();
+        assert_eq!(play.dummy(), Some(BridgeDirection::N));
+
+        // It's the dummy's turn: the declarer plays from the dummy, but the defenders can't
+        assert_eq!(
+            play.play_card(BridgeDirection::E, Card::HK),
+            Err(PlayError::OutOfTurn)
+        );
+        assert!(play.is_legal_play(BridgeDirection::S, Card::HA));
+        assert!(!play.is_legal_play(BridgeDirection::S, Card::H7));
+        assert_eq!(
+            play.legal_plays(BridgeDirection::S),
+            hand(&[Card::HA, Card::H4, Card::H3])
+        );
+        assert_eq!(play.play_card(BridgeDirection::S, Card::HA), Ok(()));
+        assert_eq!(play.current_trick()[1], (BridgeDirection::N, Card::HA));
+        assert!(!play.cards_remaining(BridgeDirection::N).contains(Card::HA));
+
+        // On the declarer's own turn, only the declarer's cards may be played
+        play.play_card(BridgeDirection::E, Card::HJ).unwrap();
+        assert_eq!(
+            play.play_card(BridgeDirection::S, Card::H4),
+            Err(PlayError::CardNotHeld)
+        );
+        play.play_card(BridgeDirection::S, Card::H5).unwrap();
+
+        // The dummy won the trick and leads; the dummy's seat may still be named
+        assert_eq!(play.play_card(BridgeDirection::N, Card::DA), Ok(()));
+    }
 }
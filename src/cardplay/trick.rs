@@ -1,4 +1,5 @@
-use crate::{turns, BridgeDirection};
+use crate::contract::Strain;
+use crate::{seat_order, turns, BridgeDirection};
 use bridge_deck::Card;
 
 #[derive(Eq, PartialEq, Debug)]
@@ -7,22 +8,74 @@ pub struct CompletedTrick {
     east: Card,
     south: Card,
     west: Card,
+    leader: BridgeDirection,
+    winner: BridgeDirection,
 }
 
 impl CompletedTrick {
-    fn new(lead: BridgeDirection, cards: Vec<Card>) -> Self {
+    /// Records a trick from the four cards, in the order they were played starting with `lead`.
+    ///
+    /// The winner is worked out straight away, so it never needs to be derived again: the highest trump wins, otherwise the highest card of the suit led.
+    pub(super) fn new(lead: BridgeDirection, cards: Vec<Card>, trump: Strain) -> Self {
         debug_assert_eq!(cards.len(), 4);
-        let mut ordered_cards = turns(lead)
-            .zip(cards.into_iter().cycle())
-            .skip_while(|(pos, _)| *pos != BridgeDirection::N)
-            .take(4)
-            .map(|(_, card)| card);
+        let (winner, _) = Self::find_winner(lead, &cards, trump);
+        let seats = seat_order(lead);
+        let card_of = |who| cards[seats.iter().position(|&seat| seat == who).unwrap()];
 
         Self {
-            north: ordered_cards.next().unwrap(),
-            east: ordered_cards.next().unwrap(),
-            south: ordered_cards.next().unwrap(),
-            west: ordered_cards.next().unwrap(),
+            north: card_of(BridgeDirection::N),
+            east: card_of(BridgeDirection::E),
+            south: card_of(BridgeDirection::S),
+            west: card_of(BridgeDirection::W),
+            leader: lead,
+            winner,
+        }
+    }
+
+    /// Works out who wins the cards played so far, in order starting with `lead`, along with the winning card: the highest trump, otherwise the highest card of the suit led.
+    ///
+    /// The trick doesn't need to be complete, so this also gives the player currently winning a trick in progress.
+    pub(super) fn find_winner(
+        lead: BridgeDirection,
+        cards: &[Card],
+        trump: Strain,
+    ) -> (BridgeDirection, Card) {
+        let trump = trump.trump_suit();
+        let beats = |card: &Card, best: &Card| {
+            if card.suit() == best.suit() {
+                card.rank() > best.rank()
+            } else {
+                Some(card.suit()) == trump
+            }
+        };
+
+        let mut played = turns(lead).zip(cards.iter());
+        let mut winner = played.next().expect("A trick has cards");
+        for (pos, card) in played {
+            if beats(card, winner.1) {
+                winner = (pos, card);
+            }
+        }
+        (winner.0, *winner.1)
+    }
+
+    /// The player who led to the trick.
+    pub fn leader(&self) -> BridgeDirection {
+        self.leader
+    }
+
+    /// The player who won the trick.
+    pub fn winner(&self) -> BridgeDirection {
+        self.winner
+    }
+
+    /// The card played to the trick by a specific player.
+    pub fn card_of(&self, who: BridgeDirection) -> Card {
+        match who {
+            BridgeDirection::N => self.north,
+            BridgeDirection::E => self.east,
+            BridgeDirection::S => self.south,
+            BridgeDirection::W => self.west,
         }
     }
 }
@@ -30,6 +83,7 @@ impl CompletedTrick {
 #[cfg(test)]
 mod tests {
     use crate::cardplay::trick::CompletedTrick;
+    use crate::contract::Strain;
     use crate::BridgeDirection;
     use bridge_deck::Card;
 
@@ -38,6 +92,7 @@ mod tests {
         let trick = CompletedTrick::new(
             BridgeDirection::S,
             vec![Card::H2, Card::H3, Card::H4, Card::H5],
+            Strain::Spades,
         );
         assert_eq!(
             trick,
@@ -46,7 +101,60 @@ mod tests {
                 east: Card::H5,
                 south: Card::H2,
                 west: Card::H3,
+                leader: BridgeDirection::S,
+                winner: BridgeDirection::E,
             }
         );
     }
+
+    #[test]
+    fn leader_and_winner() {
+        let trick = CompletedTrick::new(
+            BridgeDirection::W,
+            vec![Card::DK, Card::DA, Card::S2, Card::D3],
+            Strain::Spades,
+        );
+        assert_eq!(trick.leader(), BridgeDirection::W);
+        assert_eq!(trick.winner(), BridgeDirection::E);
+        assert_eq!(trick.card_of(BridgeDirection::W), Card::DK);
+        assert_eq!(trick.card_of(BridgeDirection::S), Card::D3);
+    }
+
+    #[test]
+    fn trumped_trick() {
+        let trick = CompletedTrick::new(
+            BridgeDirection::N,
+            vec![Card::HA, Card::H3, Card::HK, Card::S2],
+            Strain::Spades,
+        );
+        assert_eq!(trick.winner(), BridgeDirection::W);
+
+        // Overruffing beats the first ruff
+        let trick = CompletedTrick::new(
+            BridgeDirection::N,
+            vec![Card::HA, Card::S2, Card::HK, Card::S3],
+            Strain::Spades,
+        );
+        assert_eq!(trick.winner(), BridgeDirection::W);
+    }
+
+    #[test]
+    fn discard_does_not_win() {
+        let trick = CompletedTrick::new(
+            BridgeDirection::E,
+            vec![Card::C2, Card::DA, Card::C3, Card::SA],
+            Strain::Hearts,
+        );
+        assert_eq!(trick.winner(), BridgeDirection::W);
+    }
+
+    #[test]
+    fn notrump_trick() {
+        let trick = CompletedTrick::new(
+            BridgeDirection::S,
+            vec![Card::D4, Card::SA, Card::DQ, Card::D9],
+            Strain::NoTrump,
+        );
+        assert_eq!(trick.winner(), BridgeDirection::N);
+    }
 }
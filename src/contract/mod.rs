@@ -1,107 +1,210 @@
 use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
 
-use crate::auction::StrainBid;
+use crate::auction::{BidParseError, StrainBid};
 use crate::contract::util::{over_score, trick_score};
-use crate::{BridgeDirection, Vulnerability};
-use std::cmp::max;
+use crate::{BridgeDirection, Partnership, Vulnerability};
+use bridge_deck::Suit;
+use std::cmp::{max, Ordering};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
 mod util;
 
-#[derive(Debug, Eq, PartialEq)]
+/// The final contract of a board, once the auction is over.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Contract {
+    /// All four players passed, so the board isn't played
     PassedOut,
+    /// A contract was bid, and is played by its declarer
     BidContract(BidContract),
 }
 
 impl Contract {
+    /// The strain of the contract, or `None` for a passed out board.
+    pub fn denomination(&self) -> Option<Strain> {
+        match self {
+            Contract::PassedOut => None,
+            Contract::BidContract(contract) => Some(contract.strain()),
+        }
+    }
+
+    /// Returns the duplicate score for the number of tricks declarer took, from the declaring side's perspective. A passed out board scores nothing.
     pub fn get_score_for_tricks(&self, tricks_taken: usize, vulnerability: Vulnerability) -> i32 {
+        self.score_breakdown(tricks_taken, vulnerability).total()
+    }
+
+    /// Returns the score from a specific side's perspective, e.g. North-South's as on a traveller.
+    ///
+    /// [`get_score_for_tricks`](Contract::get_score_for_tricks) is from the declaring side's perspective, so the sign is flipped when `perspective` is the defending side.
+    pub fn get_score_for_tricks_from(
+        &self,
+        tricks_taken: usize,
+        vulnerability: Vulnerability,
+        perspective: Partnership,
+    ) -> i32 {
+        let score = self.get_score_for_tricks(tricks_taken, vulnerability);
         match self {
-            Contract::PassedOut => 0,
-            Contract::BidContract(actual_contract) => {
-                let tricks_needed: usize = 6 + actual_contract.level() as usize;
-
-                let vul = vulnerability.is_vulnerable(actual_contract.declarer);
-                if tricks_needed > tricks_taken {
-                    let down = tricks_taken as i32 - tricks_needed as i32;
-
-                    match actual_contract.modifier {
-                        Modifier::Pass => {
-                            let base_value = if vul { 100 } else { 50 };
-                            base_value * down
-                        }
-                        Modifier::Double => {
-                            if vul {
-                                down * 300 + 100
-                            } else {
-                                let bad = if down < -1 { max(-2, down + 1) } else { 0 };
-                                let worse = if down < -3 { down + 3 } else { 0 };
-                                worse * 300 + bad * 200 - 100
-                            }
-                        }
-                        Modifier::Redouble => {
-                            if vul {
-                                down * 600 + 200
-                            } else {
-                                let bad = if down < -1 { max(-2, down + 1) } else { 0 };
-                                let worse = if down < -3 { down + 3 } else { 0 };
-                                worse * 600 + bad * 400 - 200
-                            }
-                        }
+            Contract::BidContract(contract) if !perspective.contains(contract.declarer) => -score,
+            _ => score,
+        }
+    }
+
+    /// Splits the score for the number of tricks taken into its components, see [`ScoreBreakdown`].
+    ///
+    /// Its [`total`](ScoreBreakdown::total) is the score returned by [`get_score_for_tricks`](Contract::get_score_for_tricks). A passed out board scores nothing.
+    pub fn score_breakdown(
+        &self,
+        tricks_taken: usize,
+        vulnerability: Vulnerability,
+    ) -> ScoreBreakdown {
+        let actual_contract = match self {
+            Contract::PassedOut => return ScoreBreakdown::default(),
+            Contract::BidContract(actual_contract) => actual_contract,
+        };
+        let result = actual_contract.result(tricks_taken);
+        let vul = vulnerability.is_vulnerable(actual_contract.declarer);
+
+        if result < 0 {
+            let down = result;
+            let penalty = match actual_contract.modifier {
+                Modifier::Pass => {
+                    let base_value = if vul { 100 } else { 50 };
+                    base_value * down
+                }
+                Modifier::Double => {
+                    if vul {
+                        down * 300 + 100
+                    } else {
+                        let bad = if down < -1 { max(-2, down + 1) } else { 0 };
+                        let worse = if down < -3 { down + 3 } else { 0 };
+                        worse * 300 + bad * 200 - 100
                     }
-                } else {
-                    let overtricks = tricks_taken - tricks_needed;
-
-                    let level_bid = actual_contract.level() as usize;
-                    let multiplier = match actual_contract.modifier {
-                        Modifier::Pass => 1,
-                        Modifier::Double => 2,
-                        Modifier::Redouble => 4,
-                    };
-                    let made_score = trick_score(actual_contract.strain(), level_bid) * multiplier;
-                    let over_score = over_score(actual_contract, overtricks, vul);
-                    let is_game = made_score >= 100;
-                    let made_bonus = if is_game {
-                        if vul {
-                            500
-                        } else {
-                            300
-                        }
+                }
+                Modifier::Redouble => {
+                    if vul {
+                        down * 600 + 200
                     } else {
-                        50
-                    };
-                    let insult_bonus = match actual_contract.modifier {
-                        Modifier::Pass => 0,
-                        Modifier::Double => 50,
-                        Modifier::Redouble => 100,
-                    };
-                    let slam_bonus = match level_bid {
-                        1..=5 => 0,
-                        6 => {
-                            if vul {
-                                750
-                            } else {
-                                500
-                            }
-                        }
-                        7 => {
-                            if vul {
-                                1500
-                            } else {
-                                1000
-                            }
-                        }
-                        _ => {
-                            panic!("Invalid number of tricks")
-                        }
-                    };
-                    made_score as i32 + over_score as i32 + made_bonus + insult_bonus + slam_bonus
+                        let bad = if down < -1 { max(-2, down + 1) } else { 0 };
+                        let worse = if down < -3 { down + 3 } else { 0 };
+                        worse * 600 + bad * 400 - 200
+                    }
                 }
+            };
+            return ScoreBreakdown {
+                penalty,
+                ..Default::default()
+            };
+        }
+
+        let overtricks = result as usize;
+        let trick_score = actual_contract.trick_points();
+        let is_game = trick_score >= 100;
+        let game_bonus = if is_game {
+            if vul {
+                500
+            } else {
+                300
             }
+        } else {
+            50
+        };
+        ScoreBreakdown {
+            trick_score: trick_score as i32,
+            overtricks: over_score(actual_contract, overtricks, vul) as i32,
+            game_bonus,
+            insult_bonus: actual_contract.insult_bonus(),
+            slam_bonus: actual_contract.slam_bonus(vul),
+            penalty: 0,
         }
     }
+
+    /// Scores the contract for rubber bridge, adding any honors bonus to [`get_score_for_tricks`](Contract::get_score_for_tricks).
+    ///
+    /// The bonus goes to the side holding the honors, whether the contract makes or not, so it's subtracted when the defenders hold them. The score is from the declaring side's perspective.
+    /// Only the holdings that count in the strain played earn a bonus, see [`Honors`]. A passed out board scores nothing.
+    pub fn get_score_for_tricks_with_honors(
+        &self,
+        tricks_taken: usize,
+        vulnerability: Vulnerability,
+        honors: Honors,
+    ) -> i32 {
+        let contract = match self {
+            Contract::PassedOut => return 0,
+            Contract::BidContract(contract) => contract,
+        };
+        let score = self.get_score_for_tricks(tricks_taken, vulnerability);
+        let notrump = contract.strain() == Strain::NoTrump;
+        let (bonus, holder) = match honors {
+            Honors::Four(side) if !notrump => (100, side),
+            Honors::Five(side) if !notrump => (150, side),
+            Honors::FourAces(side) if notrump => (150, side),
+            _ => return score,
+        };
+        if holder == Partnership::of(contract.declarer) {
+            score + bonus
+        } else {
+            score - bonus
+        }
+    }
+}
+
+/// The components of a duplicate score, from the declaring side's perspective, see [`Contract::score_breakdown`].
+///
+/// A contract that makes only scores the bonuses; a defeated one only scores the penalty.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScoreBreakdown {
+    /// The points for the tricks bid and made, doubled or redoubled when applicable
+    pub trick_score: i32,
+    /// The points for the tricks made beyond the contract
+    pub overtricks: i32,
+    /// The game bonus, or the partscore bonus when the trick score falls short of a game
+    pub game_bonus: i32,
+    /// The bonus for making a doubled or redoubled contract
+    pub insult_bonus: i32,
+    /// The bonus for making a small or a grand slam
+    pub slam_bonus: i32,
+    /// The undertricks conceded to the defenders, as a negative number
+    pub penalty: i32,
+}
+
+impl ScoreBreakdown {
+    /// The score, i.e. the sum of all the components.
+    pub fn total(&self) -> i32 {
+        self.trick_score
+            + self.overtricks
+            + self.game_bonus
+            + self.insult_bonus
+            + self.slam_bonus
+            + self.penalty
+    }
+}
+
+/// Returns the lowest bid in a strain that scores a game when made: 3NT, 4♥, 4♠, 5♣ or 5♦.
+pub fn game_in(strain: Strain) -> StrainBid {
+    let level = match strain {
+        Strain::NoTrump => ContractLevel::Three,
+        Strain::Hearts | Strain::Spades => ContractLevel::Four,
+        Strain::Clubs | Strain::Diamonds => ContractLevel::Five,
+    };
+    StrainBid { level, strain }
+}
+
+/// Counts how often each strain was the final contract, e.g. over a session.
+///
+/// Passed out boards have no strain, so they are left out.
+pub fn denomination_histogram(contracts: &[Contract]) -> BTreeMap<Strain, usize> {
+    let mut histogram = BTreeMap::new();
+    for strain in contracts.iter().filter_map(Contract::denomination) {
+        *histogram.entry(strain).or_insert(0) += 1;
+    }
+    histogram
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// A contract that was bid, e.g. `4Sx N`: the last bid of the auction, whether it was doubled or redoubled, and who plays it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct BidContract {
     pub(crate) contract: StrainBid,
     pub(crate) modifier: Modifier,
@@ -109,46 +212,397 @@ pub struct BidContract {
 }
 
 impl BidContract {
+    /// The level of the contract, from one to seven.
     pub fn level(&self) -> ContractLevel {
         self.contract.level
     }
+
+    /// The strain of the contract, i.e. the trump suit or notrump.
     pub fn strain(&self) -> Strain {
         self.contract.strain
     }
+
+    /// Whether the contract was doubled or redoubled.
+    pub fn modifier(&self) -> Modifier {
+        self.modifier
+    }
+
+    /// The player who plays the contract.
+    pub fn declarer(&self) -> BridgeDirection {
+        self.declarer
+    }
+
+    /// The number of tricks declarer needs to make the contract.
+    pub fn tricks_needed(&self) -> usize {
+        6 + self.level() as usize
+    }
+
+    /// The number of tricks declarer took over or under the contract, e.g. `1` for 4♠ making 11 tricks, or `-1` for 4♠ making 9.
+    pub fn result(&self, tricks_taken: usize) -> i32 {
+        tricks_taken as i32 - self.tricks_needed() as i32
+    }
+
+    /// The result written as on a traveller: `=` for a contract made exactly, otherwise the overtricks or undertricks, e.g. `+2` or `-3`.
+    pub fn result_string(&self, tricks_taken: usize) -> String {
+        match self.result(tricks_taken) {
+            0 => "=".to_string(),
+            result => format!("{:+}", result),
+        }
+    }
+
+    /// Whether the contract is bid at game level (or higher) in its strain: 3NT, 4 of a major or 5 of a minor. Slams are games too.
+    ///
+    /// This only looks at the contract bid: a doubled partscore that scores enough trick points still earns a game bonus when made.
+    pub fn is_game(&self) -> bool {
+        self.contract >= game_in(self.strain())
+    }
+
+    /// Whether the contract is bid below game level in its strain.
+    pub fn is_partscore(&self) -> bool {
+        !self.is_game()
+    }
+
+    /// Whether the contract is a small slam, i.e. bid at the six level.
+    pub fn is_small_slam(&self) -> bool {
+        self.level() == ContractLevel::Six
+    }
+
+    /// Whether the contract is a grand slam, i.e. bid at the seven level.
+    pub fn is_grand_slam(&self) -> bool {
+        self.level() == ContractLevel::Seven
+    }
+
+    /// The points scored for the tricks bid, doubled or redoubled as needed. These are the points counting towards game.
+    pub(crate) fn trick_points(&self) -> usize {
+        let multiplier = match self.modifier {
+            Modifier::Pass => 1,
+            Modifier::Double => 2,
+            Modifier::Redouble => 4,
+        };
+        trick_score(self.strain(), self.level() as usize) * multiplier
+    }
+
+    /// The points scored for tricks taken beyond the contract.
+    pub(crate) fn overtrick_points(&self, overtricks: usize, vulnerable: bool) -> usize {
+        over_score(self, overtricks, vulnerable)
+    }
+
+    /// The bonus for making a doubled or redoubled contract.
+    pub(crate) fn insult_bonus(&self) -> i32 {
+        match self.modifier {
+            Modifier::Pass => 0,
+            Modifier::Double => 50,
+            Modifier::Redouble => 100,
+        }
+    }
+
+    /// The bonus for making a small or a grand slam.
+    pub(crate) fn slam_bonus(&self, vulnerable: bool) -> i32 {
+        match (self.is_grand_slam(), self.is_small_slam(), vulnerable) {
+            (true, _, true) => 1500,
+            (true, _, false) => 1000,
+            (_, true, true) => 750,
+            (_, true, false) => 500,
+            _ => 0,
+        }
+    }
+
+    /// Returns the score for every possible number of tricks taken by declarer, from 0 to 13.
+    ///
+    /// The scores are from the declaring side's perspective, as with [`Contract::get_score_for_tricks`].
+    pub fn score_table(&self, vulnerability: Vulnerability) -> [i32; 14] {
+        let contract = Contract::BidContract(*self);
+        let mut table = [0; 14];
+        for (tricks_taken, score) in table.iter_mut().enumerate() {
+            *score = contract.get_score_for_tricks(tricks_taken, vulnerability);
+        }
+        table
+    }
+
+    /// Returns the worst and the best possible scores for the contract, i.e. when declarer takes no tricks and all of them.
+    pub fn score_range(&self, vulnerability: Vulnerability) -> (i32, i32) {
+        let table = self.score_table(vulnerability);
+        (table[0], table[13])
+    }
+
+    /// Returns how much the last trick is worth: the difference between the score for `tricks` and the score for one trick fewer.
+    ///
+    /// The difference is largest at the boundary between making the contract and going one down. Since there's no trick below 0, asking for 0 tricks returns the worth of the first trick.
+    /// The scores are from the declaring side's perspective, as with [`Contract::get_score_for_tricks`].
+    pub fn swing_of_one_trick(&self, tricks: usize, vulnerability: Vulnerability) -> i32 {
+        let tricks = tricks.clamp(1, 13);
+        let table = self.score_table(vulnerability);
+        table[tricks] - table[tricks - 1]
+    }
+
+    /// Scores the contract after transferring tricks from the side that revoked, as the laws require for an established revoke.
+    ///
+    /// Usually one or two tricks are transferred, and none when the revoke gained nothing. The declarer can't end up with fewer than 0 or more than 13 tricks.
+    /// The score is from the declaring side's perspective, as with [`Contract::get_score_for_tricks`].
+    pub fn apply_revoke_penalty(
+        &self,
+        tricks_taken: usize,
+        vulnerability: Vulnerability,
+        revoking_side: Partnership,
+        tricks_transferred: usize,
+    ) -> i32 {
+        let adjusted_tricks = if revoking_side == Partnership::of(self.declarer) {
+            tricks_taken.saturating_sub(tricks_transferred)
+        } else {
+            (tricks_taken + tricks_transferred).min(13)
+        };
+        Contract::BidContract(*self).get_score_for_tricks(adjusted_tricks, vulnerability)
+    }
+
+    /// The player who makes the opening lead, i.e. the declarer's left-hand opponent.
+    /// The player who makes the opening lead, i.e. the declarer's left-hand opponent.
+    pub fn opening_leader(&self) -> BridgeDirection {
+        self.declarer.lho()
+    }
 }
 
+/// The strain of a contract: a trump suit, or notrump. Strains are ordered as in the auction, from clubs up to notrump.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Strain {
+    /// Clubs are trumps
     Clubs,
+    /// Diamonds are trumps
     Diamonds,
+    /// Hearts are trumps
     Hearts,
+    /// Spades are trumps
     Spades,
+    /// There are no trumps
     NoTrump,
 }
 
+impl Strain {
+    /// Iterates over the five strains, from clubs up to notrump.
+    pub fn all() -> impl Iterator<Item = Strain> {
+        [
+            Strain::Clubs,
+            Strain::Diamonds,
+            Strain::Hearts,
+            Strain::Spades,
+            Strain::NoTrump,
+        ]
+        .iter()
+        .copied()
+    }
+
+    /// The suit that is trumps when playing in this strain, if any.
+    pub(crate) fn trump_suit(self) -> Option<Suit> {
+        match self {
+            Strain::Clubs => Some(Suit::Clubs),
+            Strain::Diamonds => Some(Suit::Diamonds),
+            Strain::Hearts => Some(Suit::Hearts),
+            Strain::Spades => Some(Suit::Spades),
+            Strain::NoTrump => None,
+        }
+    }
+}
+
+impl From<Suit> for Strain {
+    fn from(suit: Suit) -> Self {
+        match suit {
+            Suit::Clubs => Strain::Clubs,
+            Suit::Diamonds => Strain::Diamonds,
+            Suit::Hearts => Strain::Hearts,
+            Suit::Spades => Strain::Spades,
+        }
+    }
+}
+
+/// The level of a contract: the number of tricks above six that declarer undertakes to take.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, FromPrimitive)]
 pub enum ContractLevel {
+    /// Seven tricks
     One = 1,
+    /// Eight tricks
     Two = 2,
+    /// Nine tricks
     Three = 3,
+    /// Ten tricks
     Four = 4,
+    /// Eleven tricks
     Five = 5,
+    /// Twelve tricks, a small slam
     Six = 6,
+    /// All thirteen tricks, a grand slam
     Seven = 7,
 }
 
+impl ContractLevel {
+    /// Iterates over the seven levels, from one up to seven.
+    pub fn all() -> impl Iterator<Item = ContractLevel> {
+        (1..=7).filter_map(FromPrimitive::from_u8)
+    }
+}
+
+/// The honors held in a single hand, which earn a bonus in rubber bridge.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Honors {
+    /// No qualifying holding
+    None,
+
+    /// Four of the five trump honors (ace, king, queen, jack and ten), worth 100. Only counts in a suit contract.
+    Four(Partnership),
+
+    /// All five trump honors, worth 150. Only counts in a suit contract.
+    Five(Partnership),
+
+    /// All four aces, worth 150. Only counts in notrump.
+    FourAces(Partnership),
+}
+
+/// Whether a contract was doubled or redoubled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Modifier {
+    /// Neither doubled nor redoubled
     Pass,
+    /// Doubled by the opponents
     Double,
+    /// Doubled, then redoubled by the declaring side
     Redouble,
 }
 
+/// Orders contracts by the bid first, then by the modifier: `4S` < `4Sx` < `4Sxx` < `4NT`.
+///
+/// The declarer doesn't rank contracts. It only breaks ties between contracts which are otherwise the same, in N, E, S, W order, so that the ordering agrees with equality.
+impl Ord for BidContract {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.contract
+            .cmp(&other.contract)
+            .then(self.modifier.cmp(&other.modifier))
+            .then((self.declarer as usize).cmp(&(other.declarer as usize)))
+    }
+}
+
+impl PartialOrd for BidContract {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Displays the strain as written on a scoresheet: `C`, `D`, `H`, `S` or `NT`.
+impl fmt::Display for Strain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strain = match self {
+            Strain::Clubs => "C",
+            Strain::Diamonds => "D",
+            Strain::Hearts => "H",
+            Strain::Spades => "S",
+            Strain::NoTrump => "NT",
+        };
+        f.write_str(strain)
+    }
+}
+
+/// Displays the contract followed by the declarer, e.g. `3NT S` or `4Sx N`.
+impl fmt::Display for BidContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifier = match self.modifier {
+            Modifier::Pass => "",
+            Modifier::Double => "x",
+            Modifier::Redouble => "xx",
+        };
+        write!(f, "{}{} {:?}", self.contract, modifier, self.declarer)
+    }
+}
+
+/// Parses a contract in the format used by its `Display` implementation, e.g. `4Sx N` or `3NT S`.
+///
+/// Letters can be in either case, e.g. `4sX n`.
+impl FromStr for BidContract {
+    type Err = ContractParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let (bid, declarer) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(bid), Some(declarer), None) => (bid, declarer),
+            _ => return Err(ContractParseError::MissingDeclarer),
+        };
+
+        let bid = bid.to_ascii_uppercase();
+        let (bid, modifier) = if let Some(bid) = bid.strip_suffix("XX") {
+            (bid, Modifier::Redouble)
+        } else if let Some(bid) = bid.strip_suffix('X') {
+            (bid, Modifier::Double)
+        } else {
+            (bid.as_str(), Modifier::Pass)
+        };
+        let contract = StrainBid::try_from(bid).map_err(ContractParseError::InvalidBid)?;
+
+        let declarer = match declarer.to_ascii_uppercase().as_str() {
+            "N" => BridgeDirection::N,
+            "E" => BridgeDirection::E,
+            "S" => BridgeDirection::S,
+            "W" => BridgeDirection::W,
+            _ => return Err(ContractParseError::InvalidDeclarer),
+        };
+
+        Ok(Self {
+            contract,
+            modifier,
+            declarer,
+        })
+    }
+}
+
+/// These are possible errors arising from reading a contract, e.g. with [`str::parse`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ContractParseError {
+    /// The text isn't a contract followed by the declarer
+    MissingDeclarer,
+
+    /// The contract isn't a valid bid, once any double or redouble is removed
+    InvalidBid(BidParseError),
+
+    /// The declarer isn't one of N, E, S or W
+    InvalidDeclarer,
+}
+
+impl fmt::Display for ContractParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractParseError::MissingDeclarer => {
+                f.write_str("a contract should be followed by its declarer, e.g. 4S N")
+            }
+            ContractParseError::InvalidBid(error) => write!(f, "invalid contract: {}", error),
+            ContractParseError::InvalidDeclarer => {
+                f.write_str("the declarer should be one of N, E, S or W")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContractParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContractParseError::InvalidBid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Contract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Contract::PassedOut => f.write_str("Passed out"),
+            Contract::BidContract(contract) => contract.fmt(f),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::contract::{BidContract, Contract, Modifier};
-    use crate::{BridgeDirection, Vulnerability};
+    use crate::auction::BidParseError;
+    use crate::contract::{
+        denomination_histogram, game_in, BidContract, Contract, ContractParseError, Honors,
+        Modifier, ScoreBreakdown, Strain,
+    };
+    use crate::{BridgeDirection, Partnership, Vulnerability};
     use std::convert::TryInto;
 
     #[test]
@@ -160,6 +614,32 @@ mod tests {
         };
     }
 
+    #[test]
+    fn opening_leader() {
+        let contract = BidContract {
+            contract: "3nt".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::W,
+        };
+        assert_eq!(contract.opening_leader(), BridgeDirection::N);
+
+        for &(declarer, leader) in [
+            (BridgeDirection::N, BridgeDirection::E),
+            (BridgeDirection::E, BridgeDirection::S),
+            (BridgeDirection::S, BridgeDirection::W),
+            (BridgeDirection::W, BridgeDirection::N),
+        ]
+        .iter()
+        {
+            let contract = BidContract {
+                declarer,
+                ..contract
+            };
+            assert_eq!(contract.opening_leader(), leader);
+            assert!(leader.is_opponent_of(declarer));
+        }
+    }
+
     #[test]
     fn score_undoubled_down() {
         let bid = BidContract {
@@ -232,6 +712,62 @@ mod tests {
         assert_eq!(contract.get_score_for_tricks(9, Vulnerability::NONE), -200);
     }
 
+    #[test]
+    fn doubled_non_vulnerable_penalty_ladder() {
+        let bid = BidContract {
+            contract: "7n".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::N,
+        };
+        let contract = Contract::BidContract(bid);
+        let penalties = [
+            100, 300, 500, 800, 1100, 1400, 1700, 2000, 2300, 2600, 2900, 3200, 3500,
+        ];
+        for (down, &penalty) in (1..=13).zip(penalties.iter()) {
+            assert_eq!(
+                contract.get_score_for_tricks(13 - down, Vulnerability::NONE),
+                -penalty,
+                "7NTX down {}",
+                down
+            );
+        }
+    }
+
+    #[test]
+    fn extreme_penalties() {
+        let doubled = Contract::BidContract(BidContract {
+            contract: "7n".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::N,
+        });
+        assert_eq!(doubled.get_score_for_tricks(0, Vulnerability::NONE), -3500);
+        assert_eq!(doubled.get_score_for_tricks(0, Vulnerability::ALL), -3800);
+
+        let redoubled = Contract::BidContract(BidContract {
+            contract: "7n".try_into().unwrap(),
+            modifier: Modifier::Redouble,
+            declarer: BridgeDirection::N,
+        });
+        assert_eq!(
+            redoubled.get_score_for_tricks(0, Vulnerability::NONE),
+            -7000
+        );
+        assert_eq!(redoubled.get_score_for_tricks(0, Vulnerability::ALL), -7600);
+        assert_eq!(
+            redoubled.get_score_for_tricks(12, Vulnerability::NONE),
+            -200
+        );
+        assert_eq!(redoubled.get_score_for_tricks(12, Vulnerability::ALL), -400);
+
+        let undoubled = Contract::BidContract(BidContract {
+            contract: "7n".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::N,
+        });
+        assert_eq!(undoubled.get_score_for_tricks(0, Vulnerability::NONE), -650);
+        assert_eq!(undoubled.get_score_for_tricks(0, Vulnerability::ALL), -1300);
+    }
+
     #[test]
     fn partscores_made_undoubled() {
         let major_bid = BidContract {
@@ -400,6 +936,373 @@ mod tests {
         assert_eq!(contract.get_score_for_tricks(13, Vulnerability::ALL), 2980);
     }
 
+    #[test]
+    fn denominations() {
+        let contract = |bid: &str| {
+            Contract::BidContract(BidContract {
+                contract: bid.try_into().unwrap(),
+                modifier: Modifier::Pass,
+                declarer: BridgeDirection::N,
+            })
+        };
+        let session = vec![
+            contract("3n"),
+            contract("4s"),
+            Contract::PassedOut,
+            contract("1n"),
+            contract("2s"),
+            contract("5d"),
+        ];
+        assert_eq!(session[0].denomination(), Some(Strain::NoTrump));
+        assert_eq!(session[2].denomination(), None);
+
+        let histogram = denomination_histogram(&session);
+        assert_eq!(histogram.get(&Strain::NoTrump), Some(&2));
+        assert_eq!(histogram.get(&Strain::Spades), Some(&2));
+        assert_eq!(histogram.get(&Strain::Diamonds), Some(&1));
+        assert_eq!(histogram.get(&Strain::Hearts), None);
+        assert_eq!(histogram.values().sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn score_range() {
+        let four_spades = BidContract {
+            contract: "4s".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::N,
+        };
+        assert_eq!(four_spades.score_range(Vulnerability::NONE), (-500, 510));
+        assert_eq!(four_spades.score_range(Vulnerability::NS), (-1000, 710));
+
+        let table = four_spades.score_table(Vulnerability::NONE);
+        assert_eq!(table[9], -50);
+        assert_eq!(table[10], 420);
+    }
+
+    #[test]
+    fn revoke_penalty() {
+        let four_spades = BidContract {
+            contract: "4s".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::N,
+        };
+        let vul = Vulnerability::NONE;
+        assert_eq!(
+            four_spades.apply_revoke_penalty(10, vul, Partnership::NS, 1),
+            -50
+        );
+        assert_eq!(
+            four_spades.apply_revoke_penalty(9, vul, Partnership::EW, 1),
+            420
+        );
+        assert_eq!(
+            four_spades.apply_revoke_penalty(10, vul, Partnership::EW, 0),
+            420
+        );
+        assert_eq!(
+            four_spades.apply_revoke_penalty(12, vul, Partnership::EW, 2),
+            510
+        );
+        assert_eq!(
+            four_spades.apply_revoke_penalty(1, vul, Partnership::NS, 2),
+            -500
+        );
+    }
+
+    #[test]
+    fn swing_of_one_trick() {
+        let four_spades = BidContract {
+            contract: "4s".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::E,
+        };
+        let vul = Vulnerability::ALL;
+        // 620 for making, -100 for one down
+        assert_eq!(four_spades.swing_of_one_trick(10, vul), 720);
+        assert_eq!(four_spades.swing_of_one_trick(11, vul), 30);
+        assert_eq!(four_spades.swing_of_one_trick(9, vul), 100);
+        assert_eq!(four_spades.swing_of_one_trick(0, vul), 100);
+    }
+
+    #[test]
+    fn game_thresholds() {
+        assert_eq!(game_in(Strain::Clubs), "5c".try_into().unwrap());
+        assert_eq!(game_in(Strain::Diamonds), "5d".try_into().unwrap());
+        assert_eq!(game_in(Strain::Hearts), "4h".try_into().unwrap());
+        assert_eq!(game_in(Strain::Spades), "4s".try_into().unwrap());
+        assert_eq!(game_in(Strain::NoTrump), "3n".try_into().unwrap());
+    }
+
+    #[test]
+    fn display() {
+        let four_spades_doubled = BidContract {
+            contract: "4s".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::N,
+        };
+        assert_eq!(four_spades_doubled.to_string(), "4Sx N");
+
+        let three_notrump = BidContract {
+            contract: "3n".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        };
+        assert_eq!(Contract::BidContract(three_notrump).to_string(), "3NT S");
+        assert_eq!(Contract::PassedOut.to_string(), "Passed out");
+    }
+
+    #[test]
+    fn honors() -> Result<(), ContractParseError> {
+        let four_spades = Contract::BidContract("4S N".parse()?);
+        let vul = Vulnerability::NONE;
+        let score =
+            |tricks, honors| four_spades.get_score_for_tricks_with_honors(tricks, vul, honors);
+
+        assert_eq!(score(10, Honors::None), 420);
+        assert_eq!(score(10, Honors::Four(Partnership::NS)), 520);
+        assert_eq!(score(10, Honors::Five(Partnership::NS)), 570);
+        // The defenders collect their honors even when the contract makes
+        assert_eq!(score(10, Honors::Four(Partnership::EW)), 320);
+        // Declarer collects them even when going down
+        assert_eq!(score(9, Honors::Four(Partnership::NS)), 50);
+        // Four aces only count in notrump
+        assert_eq!(score(10, Honors::FourAces(Partnership::NS)), 420);
+
+        let three_notrump = Contract::BidContract("3NT E".parse()?);
+        assert_eq!(
+            three_notrump.get_score_for_tricks_with_honors(
+                9,
+                vul,
+                Honors::FourAces(Partnership::EW)
+            ),
+            550
+        );
+        assert_eq!(
+            three_notrump.get_score_for_tricks_with_honors(9, vul, Honors::Five(Partnership::EW)),
+            400
+        );
+        assert_eq!(
+            Contract::PassedOut.get_score_for_tricks_with_honors(
+                0,
+                vul,
+                Honors::Five(Partnership::NS)
+            ),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn contract_kinds() -> Result<(), ContractParseError> {
+        let kinds = |text: &str| -> Result<[bool; 4], ContractParseError> {
+            let contract: BidContract = text.parse()?;
+            Ok([
+                contract.is_partscore(),
+                contract.is_game(),
+                contract.is_small_slam(),
+                contract.is_grand_slam(),
+            ])
+        };
+        assert_eq!(kinds("2NT S")?, [true, false, false, false]);
+        assert_eq!(kinds("3NT S")?, [false, true, false, false]);
+        assert_eq!(kinds("3S N")?, [true, false, false, false]);
+        assert_eq!(kinds("4Hx E")?, [false, true, false, false]);
+        assert_eq!(kinds("4D W")?, [true, false, false, false]);
+        assert_eq!(kinds("5C W")?, [false, true, false, false]);
+        assert_eq!(kinds("6C N")?, [false, true, true, false]);
+        assert_eq!(kinds("7NT S")?, [false, true, false, true]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> Result<(), ContractParseError> {
+        for &text in &["4Sx N", "3NT S", "1C E", "7NTxx W", "2D S"] {
+            assert_eq!(text.parse::<BidContract>()?.to_string(), text);
+        }
+        assert_eq!(
+            "6H W".parse::<BidContract>()?,
+            BidContract {
+                contract: "6h".try_into().unwrap(),
+                modifier: Modifier::Pass,
+                declarer: BridgeDirection::W,
+            }
+        );
+
+        assert_eq!("4sX n".parse::<BidContract>()?.to_string(), "4Sx N");
+        assert_eq!("3ntXX e".parse::<BidContract>()?.to_string(), "3NTxx E");
+
+        assert_eq!(
+            "4S Q".parse::<BidContract>(),
+            Err(ContractParseError::InvalidDeclarer)
+        );
+        assert_eq!(
+            "4Sy N".parse::<BidContract>(),
+            Err(ContractParseError::InvalidBid(
+                BidParseError::TrailingGarbage
+            ))
+        );
+        assert_eq!(
+            "4Sxxx N".parse::<BidContract>(),
+            Err(ContractParseError::InvalidBid(
+                BidParseError::TrailingGarbage
+            ))
+        );
+        assert_eq!(
+            "8S N".parse::<BidContract>(),
+            Err(ContractParseError::InvalidBid(BidParseError::BadLevel))
+        );
+        assert_eq!(
+            "4S".parse::<BidContract>(),
+            Err(ContractParseError::MissingDeclarer)
+        );
+        assert_eq!(
+            "4S N E".parse::<BidContract>(),
+            Err(ContractParseError::MissingDeclarer)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn result() -> Result<(), ContractParseError> {
+        let four_spades: BidContract = "4S N".parse()?;
+        assert_eq!(four_spades.result(10), 0);
+        assert_eq!(four_spades.result_string(10), "=");
+        assert_eq!(four_spades.result(11), 1);
+        assert_eq!(four_spades.result_string(12), "+2");
+        assert_eq!(four_spades.result(9), -1);
+        assert_eq!(four_spades.result_string(7), "-3");
+
+        let grand_slam: BidContract = "7NT S".parse()?;
+        assert_eq!(grand_slam.result_string(13), "=");
+        assert_eq!(grand_slam.result(0), -13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ordering() -> Result<(), ContractParseError> {
+        let contract = |text: &str| text.parse::<BidContract>();
+        assert!(contract("4S N")? < contract("4Sx N")?);
+        assert!(contract("4Sx N")? < contract("4Sxx N")?);
+        assert!(contract("4Sxx N")? < contract("4NT N")?);
+        assert!(contract("3NT W")? < contract("4C N")?);
+        assert!(contract("3NTxx N")? < contract("4C W")?);
+
+        let mut contracts = [contract("4Sx E")?, contract("2H S")?, contract("4S W")?];
+        contracts.sort();
+        assert_eq!(contracts.last(), Some(&contract("4Sx E")?));
+        assert_eq!(contracts.first(), Some(&contract("2H S")?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn score_from_perspective() -> Result<(), ContractParseError> {
+        let contract = |text: &str| -> Result<Contract, ContractParseError> {
+            Ok(Contract::BidContract(text.parse()?))
+        };
+        let vul = Vulnerability::NONE;
+
+        let by_north = contract("4S N")?;
+        assert_eq!(
+            by_north.get_score_for_tricks_from(10, vul, Partnership::NS),
+            420
+        );
+        assert_eq!(
+            by_north.get_score_for_tricks_from(10, vul, Partnership::EW),
+            -420
+        );
+        assert_eq!(
+            by_north.get_score_for_tricks_from(9, vul, Partnership::NS),
+            -50
+        );
+        assert_eq!(
+            by_north.get_score_for_tricks_from(9, vul, Partnership::EW),
+            50
+        );
+
+        let by_west = contract("4S W")?;
+        assert_eq!(
+            by_west.get_score_for_tricks_from(10, vul, Partnership::NS),
+            -420
+        );
+        assert_eq!(
+            by_west.get_score_for_tricks_from(10, vul, Partnership::EW),
+            420
+        );
+        assert_eq!(
+            by_west.get_score_for_tricks_from(9, vul, Partnership::NS),
+            50
+        );
+        assert_eq!(
+            by_west.get_score_for_tricks_from(9, vul, Partnership::EW),
+            -50
+        );
+
+        assert_eq!(
+            Contract::PassedOut.get_score_for_tricks_from(0, vul, Partnership::EW),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn score_breakdown() -> Result<(), ContractParseError> {
+        let breakdown =
+            |text: &str, tricks, vulnerability| -> Result<ScoreBreakdown, ContractParseError> {
+                Ok(Contract::BidContract(text.parse()?).score_breakdown(tricks, vulnerability))
+            };
+
+        let four_spades_made = breakdown("4S N", 10, Vulnerability::ALL)?;
+        assert_eq!(
+            four_spades_made,
+            ScoreBreakdown {
+                trick_score: 120,
+                game_bonus: 500,
+                ..Default::default()
+            }
+        );
+        assert_eq!(four_spades_made.total(), 620);
+
+        let doubled_slam = breakdown("6NTx E", 13, Vulnerability::NONE)?;
+        assert_eq!(
+            doubled_slam,
+            ScoreBreakdown {
+                trick_score: 380,
+                overtricks: 100,
+                game_bonus: 300,
+                insult_bonus: 50,
+                slam_bonus: 500,
+                penalty: 0,
+            }
+        );
+        assert_eq!(doubled_slam.total(), 1330);
+
+        let partscore = breakdown("2C S", 9, Vulnerability::NONE)?;
+        assert_eq!(partscore.game_bonus, 50);
+        assert_eq!(partscore.overtricks, 20);
+
+        let defeated = breakdown("3Cx W", 7, Vulnerability::EW)?;
+        assert_eq!(
+            defeated,
+            ScoreBreakdown {
+                penalty: -500,
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            Contract::PassedOut.score_breakdown(0, Vulnerability::ALL),
+            ScoreBreakdown::default()
+        );
+
+        Ok(())
+    }
+
     mod basic {
         use crate::contract::{ContractLevel, Strain};
 
@@ -411,5 +1314,23 @@ mod tests {
 
             assert!(ContractLevel::Four < ContractLevel::Six);
         }
+
+        #[test]
+        fn iterate_strains() {
+            let strains: Vec<Strain> = Strain::all().collect();
+            assert_eq!(strains.len(), 5);
+            assert_eq!(strains.first(), Some(&Strain::Clubs));
+            assert_eq!(strains.last(), Some(&Strain::NoTrump));
+            assert!(strains.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        #[test]
+        fn iterate_levels() {
+            let levels: Vec<ContractLevel> = ContractLevel::all().collect();
+            assert_eq!(levels.len(), 7);
+            assert_eq!(levels.first(), Some(&ContractLevel::One));
+            assert_eq!(levels.last(), Some(&ContractLevel::Seven));
+            assert!(levels.windows(2).all(|pair| pair[0] < pair[1]));
+        }
     }
 }
@@ -0,0 +1,126 @@
+//! Deterministic dealing, used to reproduce a specific deal from a number.
+
+use bridge_deck::{Card, Cards};
+
+use crate::evaluation::suit_cards;
+use crate::SUITS;
+
+/// The number of distinct bridge deals: 52! / (13!)^4.
+pub(crate) const DEAL_COUNT: u128 = 53_644_737_765_488_792_839_237_440_000;
+
+/// The whole deck, from the ace of spades down to the two of clubs.
+fn ordered_deck() -> Vec<Card> {
+    SUITS
+        .iter()
+        .flat_map(|&suit| {
+            let mut cards: Vec<Card> = suit_cards(&Cards::ALL, suit).collect();
+            cards.sort_by_key(|card| std::cmp::Reverse(card.rank()));
+            cards
+        })
+        .collect()
+}
+
+fn binomial(n: u128, k: u128) -> u128 {
+    (1..=k).fold(1, |result, i| result * (n - k + i) / i)
+}
+
+/// The number of ways to deal out the remaining cards, given how many cards each seat still needs.
+fn completions(needed: &[u128; 4]) -> u128 {
+    let mut left: u128 = needed.iter().sum();
+    needed.iter().fold(1, |ways, &count| {
+        let ways = ways * binomial(left, count);
+        left -= count;
+        ways
+    })
+}
+
+/// Returns the hands of North, East, South and West for the deal at an index, counted from 0.
+///
+/// The index is the deal number minus one, see [`Board::from_big_deal_number`](crate::Board::from_big_deal_number) for the numbering scheme.
+pub(crate) fn deal_from_number(number: u128) -> [Cards; 4] {
+    let mut index = number % DEAL_COUNT;
+    let mut needed = [13; 4];
+    let mut hands = [Cards::EMPTY; 4];
+
+    for card in ordered_deck() {
+        for seat in 0..4 {
+            if needed[seat] == 0 {
+                continue;
+            }
+            needed[seat] -= 1;
+            let ways = completions(&needed);
+            if index < ways {
+                hands[seat].insert(card);
+                break;
+            }
+            index -= ways;
+            needed[seat] += 1;
+        }
+    }
+
+    hands
+}
+
+/// Turns a seed into a deal number, using the SplitMix64 generator.
+///
+/// The same seed always gives the same number, on every platform, and nearby seeds give unrelated deals.
+pub(crate) fn deal_number_from_seed(seed: u64) -> u128 {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    let high = next() as u128;
+    let low = next() as u128;
+    ((high << 64) | low) % DEAL_COUNT
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::deal::{completions, deal_from_number, deal_number_from_seed, DEAL_COUNT};
+    use bridge_deck::{Card, Cards};
+
+    #[test]
+    fn counts_all_deals() {
+        assert_eq!(completions(&[13; 4]), DEAL_COUNT);
+        assert_eq!(completions(&[0, 0, 1, 0]), 1);
+        assert_eq!(completions(&[1, 0, 1, 0]), 2);
+    }
+
+    #[test]
+    fn every_deal_is_complete() {
+        for &number in &[0, 1, 12345, DEAL_COUNT / 3, DEAL_COUNT - 1, u128::MAX] {
+            let hands = deal_from_number(number);
+            assert!(hands.iter().all(|hand| hand.len() == 13));
+            let all = hands
+                .iter()
+                .fold(Cards::EMPTY, |all, &hand| all.union(hand));
+            assert_eq!(all, Cards::ALL);
+        }
+    }
+
+    #[test]
+    fn wraps_around() {
+        assert_eq!(deal_from_number(DEAL_COUNT), deal_from_number(0));
+        assert_eq!(deal_from_number(DEAL_COUNT + 7), deal_from_number(7));
+    }
+
+    #[test]
+    fn first_deals() {
+        let [_, _, south, west] = deal_from_number(1);
+        assert!(south.contains(Card::CA));
+        assert!(!south.contains(Card::D2));
+        assert!(west.contains(Card::D2));
+        assert!(!west.contains(Card::CA));
+    }
+
+    #[test]
+    fn seeded_numbers() {
+        assert_eq!(deal_number_from_seed(7), deal_number_from_seed(7));
+        assert_ne!(deal_number_from_seed(7), deal_number_from_seed(8));
+        assert!(deal_number_from_seed(u64::MAX) < DEAL_COUNT);
+    }
+}
@@ -0,0 +1,646 @@
+//! Hand evaluation helpers
+//!
+//! These functions inspect a holding of [`Cards`] and report the figures used when bidding, leading or teaching, e.g. [`longest_suit`].
+
+use bridge_deck::{Card, Cards, Suit};
+use num_traits::FromPrimitive;
+
+use crate::contract::{BidContract, ContractLevel, Strain};
+use crate::SUITS;
+
+const ACE: u8 = 14;
+const KING: u8 = 13;
+const QUEEN: u8 = 12;
+const JACK: u8 = 11;
+const TEN: u8 = 10;
+
+/// Returns the cards of a single suit found in a holding.
+pub(crate) fn suit_cards(hand: &Cards, suit: Suit) -> Cards {
+    let whole_suit = match suit {
+        Suit::Clubs => Cards::CLUBS,
+        Suit::Diamonds => Cards::DIAMONDS,
+        Suit::Hearts => Cards::HEARTS,
+        Suit::Spades => Cards::SPADES,
+    };
+    hand.intersection(whole_suit)
+}
+
+/// Splits a holding into its four suits, in spades, hearts, diamonds and clubs order.
+pub fn by_suit(hand: &Cards) -> [Cards; 4] {
+    let mut suits = [Cards::EMPTY; 4];
+    for (cards, &suit) in suits.iter_mut().zip(SUITS.iter()) {
+        *cards = suit_cards(hand, suit);
+    }
+    suits
+}
+
+/// Returns the ranks a holding has in a suit, highest first, e.g. `[14, 12, 10]` for `AQT`.
+///
+/// Notrump isn't a suit, so it has no ranks.
+///
+/// ```
+/// use bridge_backend::evaluation::ranks_in_suit;
+/// use bridge_backend::Strain;
+/// use bridge_deck::{Card, Cards};
+///
+/// let mut hand = Cards::EMPTY;
+/// for &card in &[Card::HA, Card::HQ, Card::HT, Card::S2] {
+///     hand.insert(card);
+/// }
+/// assert_eq!(ranks_in_suit(&hand, Strain::Hearts), [14, 12, 10]);
+/// assert!(ranks_in_suit(&hand, Strain::NoTrump).is_empty());
+/// ```
+pub fn ranks_in_suit(hand: &Cards, suit: Strain) -> Vec<u8> {
+    let suit = match suit.trump_suit() {
+        Some(suit) => suit,
+        None => return vec![],
+    };
+    let mut ranks: Vec<u8> = suit_cards(hand, suit).map(|card| card.rank()).collect();
+    ranks.sort_unstable_by(|a, b| b.cmp(a));
+    ranks
+}
+
+/// Returns the longest suit of a holding, along with its length.
+///
+/// Ties are broken in favour of the higher-ranking suit, so a 4-4-3-2 with four spades and four clubs reports spades.
+pub fn longest_suit(hand: &Cards) -> (Strain, usize) {
+    SUITS
+        .iter()
+        .rev()
+        .map(|&suit| (Strain::from(suit), suit_cards(hand, suit).len()))
+        .max_by_key(|&(_, length)| length)
+        .expect("There are always four suits")
+}
+
+/// Counts the cards of a specific rank in a holding.
+fn count_rank(hand: &Cards, rank: u8) -> u8 {
+    hand.filter(|card| card.rank() == rank).count() as u8
+}
+
+/// Returns the number of aces in a holding.
+pub fn aces(hand: &Cards) -> u8 {
+    count_rank(hand, ACE)
+}
+
+/// Returns the number of kings in a holding.
+pub fn kings(hand: &Cards) -> u8 {
+    count_rank(hand, KING)
+}
+
+/// Returns the number of controls in a holding, counting 2 for each ace and 1 for each king.
+///
+/// Slam bidding conventions usually ask for these, rather than for high card points.
+pub fn controls(hand: &Cards) -> u8 {
+    2 * aces(hand) + kings(hand)
+}
+
+/// Returns the high card points of a holding, using the 4-3-2-1 count.
+///
+/// Partial holdings are fine, so this also works on the cards left in a hand during play.
+pub fn hcp(hand: &Cards) -> u8 {
+    hand.map(|card| match card.rank() {
+        ACE => 4,
+        KING => 3,
+        QUEEN => 2,
+        JACK => 1,
+        _ => 0,
+    })
+    .sum()
+}
+
+/// Returns the shortness points of a hand that supports partner's trump suit: 5 for a void, 3 for a singleton and 1 for a doubleton outside trumps.
+///
+/// Shortness is worth nothing in notrump, so this is always 0 then.
+pub fn dummy_points(hand: &Cards, trump: Strain) -> u8 {
+    let trump_suit = match trump.trump_suit() {
+        Some(suit) => suit,
+        None => return 0,
+    };
+    SUITS
+        .iter()
+        .filter(|&&suit| suit != trump_suit)
+        .map(|&suit| match suit_cards(hand, suit).len() {
+            0 => 5,
+            1 => 3,
+            2 => 1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Evaluates the combined strength of a partnership, once a trump fit has been found.
+///
+/// The value is the sum of both hands' high card points. With a fit of at least eight cards in a suit, the responder's [`dummy_points`] are added, plus one point for each trump beyond the eighth.
+/// In notrump, or without a fit, only the high card points count. Around 25 points usually make a game.
+pub fn partnership_value(opener: &Cards, responder: &Cards, trump: Strain) -> u8 {
+    let high_cards = hcp(opener) + hcp(responder);
+    let fit = match trump.trump_suit() {
+        Some(suit) => suit_cards(opener, suit).len() + suit_cards(responder, suit).len(),
+        None => 0,
+    };
+    if fit >= 8 {
+        high_cards + dummy_points(responder, trump) + (fit - 8) as u8
+    } else {
+        high_cards
+    }
+}
+
+/// Returns the Losing Trick Count of a hand.
+///
+/// Only the first three cards of each suit can be losers: a void has none, a singleton one, a doubleton two, and longer suits three. Each of those is covered by an honor: the ace for a singleton, the ace and king for a doubleton, and the ace, king and queen for longer suits.
+/// So a singleton king is a loser, `Kx` counts one loser, `Qxx` two, and `AKQxx` none.
+pub fn losing_trick_count(hand: &Cards) -> u8 {
+    SUITS
+        .iter()
+        .map(|&suit| {
+            let cards = suit_cards(hand, suit);
+            let counted = cards.len().min(3);
+            let covered = [ACE, KING, QUEEN][..counted]
+                .iter()
+                .filter(|&&rank| count_rank(&cards, rank) > 0)
+                .count();
+            (counted - covered) as u8
+        })
+        .sum()
+}
+
+/// Returns the number of total tricks predicted by the Law of Total Tricks: the sum of both sides' longest trump fits.
+///
+/// According to the Law, this is roughly the number of tricks the two sides can take together, each playing in its own fit.
+pub fn total_tricks(ns_fit: usize, ew_fit: usize) -> usize {
+    ns_fit + ew_fit
+}
+
+/// Returns the level the Law of Total Tricks suggests competing to with a trump fit of a given length: as many tricks as trumps held.
+///
+/// An eight-card fit competes to the two level, a nine-card fit to the three level, and so on. Shorter fits still suggest the one level.
+pub fn law_suggestion(our_fit: usize) -> ContractLevel {
+    let level = our_fit.saturating_sub(6).clamp(1, 7);
+    FromPrimitive::from_usize(level).expect("Levels go from 1 to 7")
+}
+
+/// Suggests an opening lead against a contract, following simple textbook rules.
+///
+/// This is a heuristic teaching aid, not a double-dummy analysis: it only looks at the leader's hand, and ignores the auction and the other hands.
+/// - Against notrump, the longest suit is led, ties going to the suit with more high card points, then to the higher-ranking suit. The lead is the top of a three-card sequence such as `KQJ` or `T98`, otherwise the fourth-best card.
+/// - Against a suit contract, trumps are only led when nothing else is left. A suit headed by a two-card sequence such as `AK` or `QJ` comes first, then a doubleton, and then the longest suit as against notrump. The top of the sequence or of the doubleton is led.
+///
+/// From shorter suits, the lead is the lowest card of three headed by an honor, and the top card otherwise.
+///
+/// # Panics
+/// Panics if the hand is empty.
+pub fn standard_lead(hand: &Cards, contract: &BidContract) -> Card {
+    let trump = contract.strain().trump_suit();
+    let held: Vec<(Suit, Vec<u8>)> = SUITS
+        .iter()
+        .rev()
+        .map(|&suit| (suit, ranks_in_suit(hand, Strain::from(suit))))
+        .filter(|(_, ranks)| !ranks.is_empty())
+        .collect();
+    let side_suits: Vec<&(Suit, Vec<u8>)> = held
+        .iter()
+        .filter(|&&(suit, _)| Some(suit) != trump)
+        .collect();
+    let candidates = if side_suits.is_empty() {
+        held.iter().collect()
+    } else {
+        side_suits
+    };
+
+    let sequence = if trump.is_some() { 2 } else { 3 };
+    // Later suits win ties, so the higher-ranking suit is preferred
+    let longest = candidates
+        .iter()
+        .max_by_key(|(_, ranks)| (ranks.len(), suit_points(ranks)));
+    let (suit, ranks) = match trump {
+        None => longest,
+        Some(_) => candidates
+            .iter()
+            .filter(|(_, ranks)| is_sequence(ranks, sequence))
+            .max_by_key(|(_, ranks)| ranks[0])
+            .or_else(|| candidates.iter().rev().find(|(_, ranks)| ranks.len() == 2))
+            .or(longest),
+    }
+    .expect("The hand should hold at least one card");
+
+    let rank = if is_sequence(ranks, sequence) {
+        ranks[0]
+    } else {
+        match ranks.len() {
+            1 | 2 => ranks[0],
+            3 if ranks[0] >= JACK => ranks[2],
+            3 => ranks[0],
+            _ => ranks[3],
+        }
+    };
+    Card::new(*suit, rank)
+}
+
+/// Returns true if a suit, given by its ranks from the highest, is headed by a sequence of `length` touching cards, the highest at least a ten.
+fn is_sequence(ranks: &[u8], length: usize) -> bool {
+    ranks.len() >= length
+        && ranks[0] >= TEN
+        && ranks[..length]
+            .windows(2)
+            .all(|pair| pair[0] == pair[1] + 1)
+}
+
+/// Returns the high card points of a suit, given by its ranks.
+fn suit_points(ranks: &[u8]) -> u8 {
+    ranks.iter().map(|&rank| rank.saturating_sub(TEN)).sum()
+}
+
+/// Broad categories of hand shapes, as used when teaching bidding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HandType {
+    /// No singleton or void, and at most one doubleton: 4-3-3-3, 4-4-3-2 or 5-3-3-2
+    Balanced,
+
+    /// No singleton or void, but two doubletons and a five-card suit: 5-4-2-2
+    SemiBalanced,
+
+    /// One suit of six cards or more, and no other suit longer than three cards
+    SingleSuited,
+
+    /// Two suits of four cards or more, one of them at least five cards long, and a singleton or void
+    TwoSuited,
+
+    /// Three suits of four cards or more: 4-4-4-1 or 5-4-4-0
+    ThreeSuited,
+}
+
+/// Returns the lengths of the four suits of a holding, in spades, hearts, diamonds and clubs order.
+pub fn distribution(hand: &Cards) -> [u8; 4] {
+    let mut lengths = [0; 4];
+    for (length, cards) in lengths.iter_mut().zip(by_suit(hand).iter()) {
+        *length = cards.len() as u8;
+    }
+    lengths
+}
+
+/// Returns the lengths of the four suits of a holding, longest first, e.g. `[5, 4, 2, 2]`.
+pub fn shape_sorted(hand: &Cards) -> [u8; 4] {
+    let mut lengths = distribution(hand);
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+    lengths
+}
+
+/// Returns true for a balanced hand: no singleton or void, and at most one doubleton.
+pub fn is_balanced(hand: &Cards) -> bool {
+    hand_type(hand) == HandType::Balanced
+}
+
+/// Classifies a hand by its shape. See [`HandType`] for the exact patterns.
+pub fn hand_type(hand: &Cards) -> HandType {
+    let [longest, second, third, shortest] = shape_sorted(hand);
+    if shortest >= 2 && third >= 3 {
+        HandType::Balanced
+    } else if longest >= 6 && second <= 3 {
+        HandType::SingleSuited
+    } else if third >= 4 {
+        HandType::ThreeSuited
+    } else if shortest >= 2 {
+        HandType::SemiBalanced
+    } else {
+        HandType::TwoSuited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::{BidContract, ContractLevel, ContractParseError, Strain};
+    use crate::evaluation::{
+        aces, by_suit, controls, distribution, dummy_points, hand_type, hcp, is_balanced, kings,
+        law_suggestion, longest_suit, losing_trick_count, partnership_value, ranks_in_suit,
+        shape_sorted, standard_lead, total_tricks, HandType,
+    };
+    use bridge_deck::{Card, Cards};
+
+    fn hand(cards: &[Card]) -> Cards {
+        let mut hand = Cards::EMPTY;
+        for &card in cards {
+            hand.insert(card);
+        }
+        hand
+    }
+
+    #[test]
+    fn longest() {
+        let six_hearts = hand(&[
+            Card::SA,
+            Card::S2,
+            Card::HK,
+            Card::HQ,
+            Card::H9,
+            Card::H7,
+            Card::H5,
+            Card::H3,
+            Card::DA,
+            Card::D4,
+            Card::C8,
+            Card::C6,
+            Card::C2,
+        ]);
+        assert_eq!(longest_suit(&six_hearts), (Strain::Hearts, 6));
+    }
+
+    #[test]
+    fn longest_prefers_higher_suit() {
+        let black_four_suiter = hand(&[
+            Card::SA,
+            Card::S8,
+            Card::S5,
+            Card::S2,
+            Card::HK,
+            Card::HQ,
+            Card::H9,
+            Card::D7,
+            Card::D4,
+            Card::CQ,
+            Card::C8,
+            Card::C6,
+            Card::C2,
+        ]);
+        assert_eq!(longest_suit(&black_four_suiter), (Strain::Spades, 4));
+        assert_eq!(longest_suit(&Cards::EMPTY), (Strain::Spades, 0));
+    }
+
+    #[test]
+    fn controls_count() {
+        let two_aces_one_king = hand(&[
+            Card::SA,
+            Card::SK,
+            Card::S2,
+            Card::HQ,
+            Card::HJ,
+            Card::H9,
+            Card::DA,
+            Card::D4,
+            Card::C8,
+            Card::C6,
+            Card::C5,
+            Card::C3,
+            Card::C2,
+        ]);
+        assert_eq!(aces(&two_aces_one_king), 2);
+        assert_eq!(kings(&two_aces_one_king), 1);
+        assert_eq!(controls(&two_aces_one_king), 5);
+
+        assert_eq!(aces(&Cards::ALL), 4);
+        assert_eq!(kings(&Cards::ALL), 4);
+        assert_eq!(controls(&Cards::ALL), 12);
+        assert_eq!(controls(&Cards::EMPTY), 0);
+    }
+
+    #[test]
+    fn high_card_points() {
+        let hand = hand(&[Card::SA, Card::SK, Card::HQ, Card::DJ, Card::CT]);
+        assert_eq!(hcp(&hand), 10);
+        assert_eq!(hcp(&Cards::EMPTY), 0);
+        assert_eq!(hcp(&Cards::ALL), 40);
+    }
+
+    #[test]
+    fn game_going_fit() {
+        let opener = hand(&[
+            Card::SA,
+            Card::SK,
+            Card::SJ,
+            Card::S6,
+            Card::S2,
+            Card::HK,
+            Card::HQ,
+            Card::H4,
+            Card::D9,
+            Card::D3,
+            Card::CQ,
+            Card::C7,
+            Card::C2,
+        ]);
+        let responder = hand(&[
+            Card::SQ,
+            Card::S8,
+            Card::S7,
+            Card::S3,
+            Card::HA,
+            Card::H9,
+            Card::H6,
+            Card::H5,
+            Card::DK,
+            Card::DJ,
+            Card::D8,
+            Card::D4,
+            Card::C5,
+        ]);
+        assert_eq!(hcp(&opener), 15);
+        assert_eq!(hcp(&responder), 10);
+        assert_eq!(dummy_points(&responder, Strain::Spades), 3);
+        assert_eq!(dummy_points(&responder, Strain::NoTrump), 0);
+
+        // 25 HCP, a singleton club and a ninth trump
+        assert_eq!(partnership_value(&opener, &responder, Strain::Spades), 29);
+        // No fit in hearts, and no shortness value in notrump
+        assert_eq!(partnership_value(&opener, &responder, Strain::Hearts), 25);
+        assert_eq!(partnership_value(&opener, &responder, Strain::NoTrump), 25);
+    }
+
+    #[test]
+    fn losing_tricks() {
+        #[rustfmt::skip]
+        let six_losers = hand(&[
+            Card::SA, Card::SK, Card::SQ, Card::S5, Card::S2,
+            Card::HK, Card::H7, Card::H3,
+            Card::D8,
+            Card::CJ, Card::C9, Card::C4, Card::C2,
+        ]);
+        assert_eq!(losing_trick_count(&six_losers), 6);
+
+        // A singleton king, ace-queen doubleton, queen third and a long suit headed by the ace and king
+        #[rustfmt::skip]
+        let short_honors = hand(&[
+            Card::SK,
+            Card::HA, Card::HQ,
+            Card::DQ, Card::D6, Card::D4,
+            Card::CA, Card::CK, Card::CJ, Card::C8, Card::C7, Card::C5, Card::C2,
+        ]);
+        assert_eq!(losing_trick_count(&short_honors), 5);
+
+        #[rustfmt::skip]
+        let with_void = hand(&[
+            Card::H3, Card::H2,
+            Card::DK, Card::DQ, Card::DJ, Card::DT, Card::D9,
+            Card::CA, Card::C8, Card::C7, Card::C6, Card::C5, Card::C4,
+        ]);
+        assert_eq!(losing_trick_count(&with_void), 5);
+
+        #[rustfmt::skip]
+        let no_honors = hand(&[
+            Card::S9, Card::S8, Card::S7, Card::S6,
+            Card::H9, Card::H8, Card::H7,
+            Card::D9, Card::D8, Card::D7,
+            Card::C9, Card::C8, Card::C7,
+        ]);
+        assert_eq!(losing_trick_count(&no_honors), 12);
+        assert_eq!(losing_trick_count(&Cards::EMPTY), 0);
+    }
+
+    #[test]
+    fn law_of_total_tricks() {
+        assert_eq!(total_tricks(9, 8), 17);
+        assert_eq!(law_suggestion(8), ContractLevel::Two);
+        assert_eq!(law_suggestion(9), ContractLevel::Three);
+        assert_eq!(law_suggestion(10), ContractLevel::Four);
+        assert_eq!(law_suggestion(5), ContractLevel::One);
+        assert_eq!(law_suggestion(13), ContractLevel::Seven);
+    }
+
+    #[test]
+    fn shapes() {
+        #[rustfmt::skip]
+        let four_three_three_three = hand(&[
+            Card::SA, Card::SK, Card::S7,
+            Card::HQ, Card::HJ, Card::H9,
+            Card::D8, Card::D4, Card::D3, Card::D2,
+            Card::C6, Card::C5, Card::C2,
+        ]);
+        assert_eq!(distribution(&four_three_three_three), [3, 3, 4, 3]);
+        assert_eq!(shape_sorted(&four_three_three_three), [4, 3, 3, 3]);
+        assert!(is_balanced(&four_three_three_three));
+
+        #[rustfmt::skip]
+        let five_four_three_one = hand(&[
+            Card::SA,
+            Card::HK, Card::HQ, Card::H9, Card::H6, Card::H5,
+            Card::DA, Card::D8, Card::D4,
+            Card::CK, Card::C7, Card::C5, Card::C2,
+        ]);
+        assert_eq!(distribution(&five_four_three_one), [1, 5, 3, 4]);
+        assert_eq!(shape_sorted(&five_four_three_one), [5, 4, 3, 1]);
+        assert!(!is_balanced(&five_four_three_one));
+
+        #[rustfmt::skip]
+        let club_void = hand(&[
+            Card::SA, Card::SK, Card::SQ, Card::S7, Card::S5,
+            Card::HK, Card::HQ, Card::H9, Card::H6,
+            Card::DA, Card::D8, Card::D4, Card::D3,
+        ]);
+        assert_eq!(distribution(&club_void), [5, 4, 4, 0]);
+        assert_eq!(shape_sorted(&club_void), [5, 4, 4, 0]);
+        assert!(!is_balanced(&club_void));
+    }
+
+    #[test]
+    fn hand_types() {
+        #[rustfmt::skip]
+        let four_three_three_three = hand(&[
+            Card::SA, Card::SK, Card::S7, Card::S2,
+            Card::HQ, Card::HJ, Card::H9,
+            Card::D8, Card::D4, Card::D3,
+            Card::C6, Card::C5, Card::C2,
+        ]);
+        assert_eq!(hand_type(&four_three_three_three), HandType::Balanced);
+
+        #[rustfmt::skip]
+        let six_three_two_two = hand(&[
+            Card::HA, Card::HK, Card::HT, Card::H8, Card::H7, Card::H2,
+            Card::SQ, Card::S9, Card::S4,
+            Card::D8, Card::D4,
+            Card::C6, Card::C5,
+        ]);
+        assert_eq!(hand_type(&six_three_two_two), HandType::SingleSuited);
+
+        #[rustfmt::skip]
+        let five_five_two_one = hand(&[
+            Card::SA, Card::SK, Card::ST, Card::S8, Card::S7,
+            Card::DQ, Card::D9, Card::D6, Card::D4, Card::D3,
+            Card::H8, Card::H4,
+            Card::C6,
+        ]);
+        assert_eq!(hand_type(&five_five_two_one), HandType::TwoSuited);
+
+        #[rustfmt::skip]
+        let five_four_two_two = hand(&[
+            Card::SA, Card::SK, Card::ST, Card::S8, Card::S7,
+            Card::DQ, Card::D9, Card::D6, Card::D4,
+            Card::H8, Card::H4,
+            Card::C6, Card::C3,
+        ]);
+        assert_eq!(hand_type(&five_four_two_two), HandType::SemiBalanced);
+
+        #[rustfmt::skip]
+        let four_four_four_one = hand(&[
+            Card::SA, Card::SK, Card::ST, Card::S8,
+            Card::DQ, Card::D9, Card::D6, Card::D4,
+            Card::H8, Card::H4, Card::H3, Card::H2,
+            Card::C6,
+        ]);
+        assert_eq!(hand_type(&four_four_four_one), HandType::ThreeSuited);
+    }
+
+    #[test]
+    fn split_by_suit() {
+        #[rustfmt::skip]
+        let five_four_three_one = hand(&[
+            Card::SA,
+            Card::HK, Card::HQ, Card::H9, Card::H6, Card::H5,
+            Card::DA, Card::D8, Card::D4,
+            Card::CK, Card::C7, Card::C5, Card::C2,
+        ]);
+        let [spades, hearts, diamonds, clubs] = by_suit(&five_four_three_one);
+        assert_eq!(spades, hand(&[Card::SA]));
+        assert_eq!(diamonds, hand(&[Card::DA, Card::D8, Card::D4]));
+        assert_eq!(
+            spades.union(hearts).union(diamonds).union(clubs),
+            five_four_three_one
+        );
+        assert_eq!(
+            by_suit(&Cards::ALL),
+            [Cards::SPADES, Cards::HEARTS, Cards::DIAMONDS, Cards::CLUBS]
+        );
+
+        assert_eq!(
+            ranks_in_suit(&five_four_three_one, Strain::Hearts),
+            [13, 12, 9, 6, 5]
+        );
+        assert_eq!(
+            ranks_in_suit(&five_four_three_one, Strain::Clubs),
+            [13, 7, 5, 2]
+        );
+        assert_eq!(ranks_in_suit(&Cards::EMPTY, Strain::Spades), []);
+        assert_eq!(ranks_in_suit(&Cards::ALL, Strain::NoTrump), []);
+    }
+
+    #[test]
+    fn opening_leads() -> Result<(), ContractParseError> {
+        let four_spades: BidContract = "4S N".parse()?;
+        let three_notrump: BidContract = "3NT N".parse()?;
+
+        #[rustfmt::skip]
+        let heart_sequence = hand(&[
+            Card::SA, Card::S3,
+            Card::HK, Card::HQ, Card::HJ, Card::H4,
+            Card::DT, Card::D8, Card::D6, Card::D2,
+            Card::C9, Card::C7, Card::C5,
+        ]);
+        assert_eq!(standard_lead(&heart_sequence, &four_spades), Card::HK);
+        assert_eq!(standard_lead(&heart_sequence, &three_notrump), Card::HK);
+
+        #[rustfmt::skip]
+        let long_spades = hand(&[
+            Card::SK, Card::SJ, Card::S8, Card::S6, Card::S3,
+            Card::HQ, Card::H7, Card::H2,
+            Card::DA, Card::D5, Card::D4,
+            Card::C9, Card::C2,
+        ]);
+        // Fourth-best of the longest suit against notrump, the doubleton against a suit contract
+        assert_eq!(standard_lead(&long_spades, &three_notrump), Card::S6);
+        assert_eq!(standard_lead(&long_spades, &"4H S".parse()?), Card::C9);
+
+        // Trumps are led when nothing else is left, and an honor heads a low card from three
+        let trumps_only = hand(&[Card::SQ, Card::S7, Card::S4]);
+        assert_eq!(standard_lead(&trumps_only, &four_spades), Card::S4);
+        assert_eq!(standard_lead(&trumps_only, &three_notrump), Card::S4);
+
+        Ok(())
+    }
+}
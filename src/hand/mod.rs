@@ -0,0 +1,78 @@
+//! Complete hands of 13 cards, checked once so they can be evaluated without further validation.
+
+use bridge_deck::{Card, Cards};
+
+use crate::{evaluation, DealError};
+
+/// A full bridge hand: exactly 13 cards, as dealt to one player.
+///
+/// Unlike bare [`Cards`], which may be any holding (a suit, a trick, the cards left during play), a `Hand` is always complete.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Hand(Cards);
+
+impl Hand {
+    /// Wraps a holding as a hand.
+    ///
+    /// Returns [`DealError::WrongHandSize`] unless it holds exactly 13 cards.
+    pub fn try_new(cards: Cards) -> Result<Self, DealError> {
+        match cards.len() {
+            13 => Ok(Self(cards)),
+            len => Err(DealError::WrongHandSize(len)),
+        }
+    }
+
+    /// Returns the cards in the hand.
+    pub fn cards(&self) -> Cards {
+        self.0
+    }
+
+    /// Returns true if the hand holds a specific card.
+    pub fn contains(&self, card: Card) -> bool {
+        self.0.contains(card)
+    }
+
+    /// Returns the high card points of the hand, see [`evaluation::hcp`].
+    pub fn hcp(&self) -> u8 {
+        evaluation::hcp(&self.0)
+    }
+
+    /// Returns the lengths of the four suits, see [`evaluation::distribution`].
+    pub fn distribution(&self) -> [u8; 4] {
+        evaluation::distribution(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::suited_board;
+    use crate::{DealError, Hand};
+    use bridge_deck::{Card, Cards};
+
+    #[test]
+    fn try_new() {
+        assert_eq!(Hand::try_new(Cards::ALL), Err(DealError::WrongHandSize(52)));
+        assert_eq!(
+            Hand::try_new(Cards::EMPTY),
+            Err(DealError::WrongHandSize(0))
+        );
+
+        let hand = Hand::try_new(Cards::SPADES).unwrap();
+        assert_eq!(hand.cards(), Cards::SPADES);
+        assert!(hand.contains(Card::SA));
+        assert!(!hand.contains(Card::HA));
+        assert_eq!(hand.hcp(), 10);
+        assert_eq!(hand.distribution(), [13, 0, 0, 0]);
+    }
+
+    #[test]
+    fn board_hands() {
+        let mut board = suited_board();
+        assert_eq!(board.north_hand().unwrap().cards(), Cards::SPADES);
+        assert_eq!(board.east_hand().unwrap().distribution(), [0, 13, 0, 0]);
+        assert_eq!(board.south_hand().unwrap().cards(), board.south);
+        assert!(board.west_hand().unwrap().contains(Card::C2));
+
+        board.west.remove(Card::C2);
+        assert_eq!(board.west_hand(), Err(DealError::WrongHandSize(12)));
+    }
+}
@@ -5,15 +5,30 @@
 //! A state machine for the Bridge card game.
 
 mod contract;
-use contract::Contract;
+pub use contract::{
+    BidContract, Contract, ContractLevel, ContractParseError, Honors, Modifier, ScoreBreakdown,
+    Strain,
+};
 
 pub mod auction;
 pub use auction::Auction;
 
 mod cardplay;
+pub use cardplay::{Cardplay, ContractFate, EndgameStatus, PlayError, RevokePolicy};
 
-use bridge_deck::Cards;
-use cardplay::Cardplay;
+mod deal;
+
+mod hand;
+pub use hand::Hand;
+
+pub mod evaluation;
+
+pub mod scoring;
+
+use auction::Bid;
+use bridge_deck::{Card, Cards, Suit};
+use std::fmt;
+use std::ops::RangeInclusive;
 
 /// Represents a bridge board.
 ///
@@ -51,6 +66,289 @@ impl Board {
         }
     }
 
+    /// Generates a single board, like [`new`](Board::new), after checking the board number.
+    ///
+    /// Boards are numbered from 1, so this returns [`DealError::InvalidBoardNumber`] for board 0.
+    pub fn try_new(number: usize) -> Result<Self, DealError> {
+        match number {
+            0 => Err(DealError::InvalidBoardNumber),
+            _ => Ok(Self::new(number)),
+        }
+    }
+
+    /// Deals a board deterministically from a deal number, as Big Deal and other dealing programs do.
+    ///
+    /// This follows the deal numbering published by Richard Pavlicek, which numbers every one of the D = 52! / (13!)^4 possible deals from 1 to D, so a deal number from another tool using it gives the same four hands here. Numbers past D wrap around, and 0 is the same as D.
+    ///
+    /// The numbering lists all deals in lexicographic order:
+    /// 1. The cards are taken in order, from the ace of spades down to the two of spades, then hearts, diamonds and clubs in the same way.
+    /// 2. Each card is given to one of North, East, South or West, in that order of preference, provided that player still holds fewer than 13 cards.
+    /// 3. Deal 1 gives each card to the first player available, i.e. North holds all the spades, East the hearts, South the diamonds and West the clubs. Each following number is the next deal in that order, up to deal D where North holds the clubs, East the diamonds, South the hearts and West the spades.
+    ///
+    /// In practice, for each card in turn, the deals left are split between North, East, South and West in proportion to the cards each of them still needs, and the card goes to the player whose share holds the deal number.
+    ///
+    /// ```
+    /// use bridge_backend::Board;
+    /// use bridge_deck::Card;
+    ///
+    /// let board = Board::from_big_deal_number(1, 1);
+    /// assert!(board.north.contains(Card::SA));
+    /// assert!(board.west.contains(Card::C2));
+    /// ```
+    pub fn from_big_deal_number(number: usize, big_deal: u128) -> Self {
+        let index = (big_deal % deal::DEAL_COUNT + deal::DEAL_COUNT - 1) % deal::DEAL_COUNT;
+        let [north, east, south, west] = deal::deal_from_number(index);
+
+        Self {
+            north,
+            east,
+            south,
+            west,
+            number,
+        }
+    }
+
+    /// Deals a board reproducibly from a seed: the same seed always gives the same four hands.
+    ///
+    /// Unlike [`from_big_deal_number`](Board::from_big_deal_number), consecutive seeds give unrelated deals, which makes seeds handy for tests and for sharing a deal.
+    ///
+    /// ```
+    /// use bridge_backend::Board;
+    ///
+    /// let board = Board::from_seed(7, 42);
+    /// assert_eq!(board.north, Board::from_seed(7, 42).north);
+    /// ```
+    pub fn from_seed(number: usize, seed: u64) -> Self {
+        Self::from_big_deal_number(number, deal::deal_number_from_seed(seed) + 1)
+    }
+
+    /// Deals a session of boards, numbered from 1 to `count`, each dealt independently at random.
+    pub fn session(count: usize) -> Vec<Self> {
+        (1..=count).map(Self::new).collect()
+    }
+
+    /// Deals a session of boards reproducibly from a seed, like [`session`](Board::session) and [`from_seed`](Board::from_seed).
+    ///
+    /// Every board is dealt from its own seed, mixed from the session's seed and the board number.
+    pub fn session_from_seed(count: usize, seed: u64) -> Vec<Self> {
+        (1..=count)
+            .map(|number| {
+                let board_seed = seed ^ (number as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+                Self::from_seed(number, board_seed)
+            })
+            .collect()
+    }
+
+    /// Deals a board around a known hand: `known` holds exactly `hand`, and the other 39 cards are dealt randomly to the other three players.
+    ///
+    /// Returns an error unless `hand` holds exactly 13 cards.
+    pub fn deal_around(
+        number: usize,
+        known: BridgeDirection,
+        hand: Cards,
+    ) -> Result<Self, DealError> {
+        if hand.len() != 13 {
+            return Err(DealError::WrongHandSize(hand.len()));
+        }
+
+        let mut rest = Cards::ALL;
+        for card in hand {
+            rest.remove(card);
+        }
+        let mut deal = |who| {
+            if who == known {
+                hand
+            } else {
+                rest.pick(13).expect("Should be able to get 13 cards")
+            }
+        };
+
+        Ok(Self {
+            north: deal(BridgeDirection::N),
+            east: deal(BridgeDirection::E),
+            south: deal(BridgeDirection::S),
+            west: deal(BridgeDirection::W),
+            number,
+        })
+    }
+
+    /// Deals random boards until one fits a bidding theme, for teaching.
+    ///
+    /// See [`DealTheme`] for the constraints of each theme. Gives up with [`DealError::TooManyAttempts`] after [`MAX_DEAL_ATTEMPTS`] boards, which only happens when a theme is extremely rare.
+    pub fn themed_deal(theme: DealTheme, number: usize) -> Result<Self, DealError> {
+        (0..MAX_DEAL_ATTEMPTS)
+            .map(|_| Self::new(number))
+            .find(|board| theme.matches(board))
+            .ok_or(DealError::TooManyAttempts)
+    }
+
+    /// Deals random boards until a player's high card points fall within a range, e.g. `15..=17` for a strong notrump opening.
+    ///
+    /// Gives up with [`DealError::TooManyAttempts`] after [`MAX_DEAL_ATTEMPTS`] boards, e.g. when the range is empty or out of reach.
+    pub fn deal_constrained(
+        number: usize,
+        seat: BridgeDirection,
+        hcp_range: RangeInclusive<u8>,
+    ) -> Result<Self, DealError> {
+        (0..MAX_DEAL_ATTEMPTS)
+            .map(|_| Self::new(number))
+            .find(|board| hcp_range.contains(&board.hcp(seat)))
+            .ok_or(DealError::TooManyAttempts)
+    }
+
+    /// Reads a deal in the PBN format, e.g. `N:AKQ2.T98.J74.A86 ...`.
+    ///
+    /// The deal starts with the seat of the first hand and a colon, followed by the four hands separated by spaces, clockwise. Each hand lists its spades, hearts, diamonds and clubs, separated by dots. Ranks are written `AKQJT98765432`, and a void is an empty suit.
+    ///
+    /// Returns an error unless every hand holds 13 cards, and every card of the deck is dealt exactly once.
+    ///
+    /// ```
+    /// use bridge_backend::Board;
+    /// use bridge_deck::Card;
+    ///
+    /// let deal = "W:KQT2.AT.J6542.85 A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764";
+    /// let board = Board::from_pbn(1, deal).unwrap();
+    /// assert!(board.north.contains(Card::SA));
+    /// assert!(board.west.contains(Card::SK));
+    /// assert_eq!(board.to_pbn(), "N:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.85");
+    /// ```
+    pub fn from_pbn(number: usize, deal: &str) -> Result<Self, ParseError> {
+        let mut parts = deal.trim().splitn(2, ':');
+        let first = match parts.next() {
+            Some("N") => BridgeDirection::N,
+            Some("E") => BridgeDirection::E,
+            Some("S") => BridgeDirection::S,
+            Some("W") => BridgeDirection::W,
+            _ => return Err(ParseError::InvalidFirstHand),
+        };
+        let hands: Vec<&str> = parts.next().unwrap_or("").split_whitespace().collect();
+        if hands.len() != 4 {
+            return Err(ParseError::WrongFormat);
+        }
+
+        let mut dealt = Cards::EMPTY;
+        let mut board = Self {
+            north: Cards::EMPTY,
+            east: Cards::EMPTY,
+            south: Cards::EMPTY,
+            west: Cards::EMPTY,
+            number,
+        };
+        for (who, hand) in turns(first).zip(hands) {
+            let suits: Vec<&str> = hand.split('.').collect();
+            if suits.len() != 4 {
+                return Err(ParseError::WrongFormat);
+            }
+
+            let mut cards = Cards::EMPTY;
+            for (&suit, ranks) in SUITS.iter().zip(suits) {
+                for rank in ranks.chars() {
+                    let rank = rank_from_char(rank).ok_or(ParseError::InvalidRank(rank))?;
+                    let card = Card::new(suit, rank);
+                    if dealt.contains(card) {
+                        return Err(ParseError::DuplicateCard(card));
+                    }
+                    dealt.insert(card);
+                    cards.insert(card);
+                }
+            }
+            if cards.len() != 13 {
+                return Err(ParseError::WrongHandSize(who, cards.len()));
+            }
+
+            match who {
+                BridgeDirection::N => board.north = cards,
+                BridgeDirection::E => board.east = cards,
+                BridgeDirection::S => board.south = cards,
+                BridgeDirection::W => board.west = cards,
+            }
+        }
+        Ok(board)
+    }
+
+    /// Writes the deal in the PBN format, starting with North. See [`from_pbn`](Board::from_pbn).
+    pub fn to_pbn(&self) -> String {
+        let hands: Vec<String> = seat_order(BridgeDirection::N)
+            .iter()
+            .map(|&who| {
+                let hand = self.hand(who);
+                let suits: Vec<String> = SUITS.iter().map(|&suit| holding(&hand, suit)).collect();
+                suits.join(".")
+            })
+            .collect();
+        format!("N:{}", hands.join(" "))
+    }
+
+    /// Returns the cards held by a specific player.
+    pub fn hand(&self, who: BridgeDirection) -> Cards {
+        match who {
+            BridgeDirection::N => self.north,
+            BridgeDirection::E => self.east,
+            BridgeDirection::S => self.south,
+            BridgeDirection::W => self.west,
+        }
+    }
+
+    /// Returns North's hand, see [`Hand`].
+    ///
+    /// The fields of a board are public, so they may hold any cards: returns [`DealError::WrongHandSize`] unless the player holds exactly 13.
+    pub fn north_hand(&self) -> Result<Hand, DealError> {
+        Hand::try_new(self.north)
+    }
+
+    /// Returns East's hand, see [`Hand`].
+    ///
+    /// Returns an error unless the player holds exactly 13 cards, see [`north_hand`](Board::north_hand).
+    pub fn east_hand(&self) -> Result<Hand, DealError> {
+        Hand::try_new(self.east)
+    }
+
+    /// Returns South's hand, see [`Hand`].
+    ///
+    /// Returns an error unless the player holds exactly 13 cards, see [`north_hand`](Board::north_hand).
+    pub fn south_hand(&self) -> Result<Hand, DealError> {
+        Hand::try_new(self.south)
+    }
+
+    /// Returns West's hand, see [`Hand`].
+    ///
+    /// Returns an error unless the player holds exactly 13 cards, see [`north_hand`](Board::north_hand).
+    pub fn west_hand(&self) -> Result<Hand, DealError> {
+        Hand::try_new(self.west)
+    }
+
+    /// Returns the same board with the two sides' cards swapped, for replaying it in the other room of a team match.
+    ///
+    /// North and East exchange their cards, and so do South and West. Mirroring twice gives back the original board. The board number, and with it the dealer and vulnerability, is unchanged.
+    pub fn mirror(&self) -> Board {
+        Self {
+            north: self.east,
+            east: self.north,
+            south: self.west,
+            west: self.south,
+            number: self.number,
+        }
+    }
+
+    /// Returns true if this board comes right after `previous` in a session.
+    ///
+    /// Since the dealer and the vulnerability are derived from the board number, the boards follow each other when their numbers do; the dealer then moves one seat clockwise, and the vulnerability follows its 16-board cycle.
+    pub fn follows(&self, previous: &Board) -> bool {
+        self.number == previous.number + 1
+    }
+
+    /// Returns the high card points held by a specific player, see [`evaluation::hcp`].
+    pub fn hcp(&self, who: BridgeDirection) -> u8 {
+        evaluation::hcp(&self.hand(who))
+    }
+
+    /// Returns the opening leader's longest suit and its length.
+    ///
+    /// Useful for sorting lead problems, e.g. finding deals where the leader holds a six-card suit.
+    pub fn leader_longest_suit(&self, contract: &BidContract) -> (Strain, usize) {
+        evaluation::longest_suit(&self.hand(contract.opening_leader()))
+    }
+
     /// Returns this board's vulnerability, according to the rules of the game
     ///
     /// ```
@@ -58,14 +356,14 @@ impl Board {
     ///
     /// assert_eq!(Board::new(7).vulnerability(), Vulnerability::ALL);
     /// assert_eq!(Board::new(99).vulnerability(), Vulnerability::EW);
+    ///
+    /// // The board is only borrowed, so its hands are still available
+    /// let board = Board::new(2);
+    /// assert_eq!(board.vulnerability(), Vulnerability::NS);
+    /// assert_eq!(board.north.len(), 13);
     /// ```
-    pub fn vulnerability(self) -> Vulnerability {
-        match self.number % 16 {
-            1 | 8 | 11 | 14 => Vulnerability::NONE,
-            2 | 5 | 12 | 15 => Vulnerability::NS,
-            3 | 6 | 9 | 0 => Vulnerability::EW,
-            _ => Vulnerability::ALL,
-        }
+    pub fn vulnerability(&self) -> Vulnerability {
+        vulnerability_for(self.number)
     }
 
     /// Returns this board's dealer, according to the rules of the game
@@ -77,17 +375,201 @@ impl Board {
     /// assert_eq!(Board::new(2).dealer(), BridgeDirection::E);
     /// assert_eq!(Board::new(31).dealer(), BridgeDirection::S);
     /// assert_eq!(Board::new(136).dealer(), BridgeDirection::W);
+    ///
+    /// let board = Board::new(3);
+    /// assert_eq!(board.dealer(), BridgeDirection::S);
+    /// assert_eq!(board.south.len(), 13);
     /// ```
-    pub fn dealer(self) -> BridgeDirection {
-        match self.number % 4 {
-            1 => BridgeDirection::N,
-            2 => BridgeDirection::E,
-            3 => BridgeDirection::S,
-            _ => BridgeDirection::W,
+    pub fn dealer(&self) -> BridgeDirection {
+        dealer_for(self.number)
+    }
+}
+
+/// Formats one suit of a hand as a line of a hand diagram, e.g. `♠ AKT2`, or `♠ -` for a void.
+fn holding_line(hand: &Cards, suit: Suit) -> String {
+    let symbol = match suit {
+        Suit::Spades => '♠',
+        Suit::Hearts => '♥',
+        Suit::Diamonds => '♦',
+        Suit::Clubs => '♣',
+    };
+    let ranks = holding(hand, suit);
+    if ranks.is_empty() {
+        format!("{} -", symbol)
+    } else {
+        format!("{} {}", symbol, ranks)
+    }
+}
+
+/// Lists the ranks held in one suit, from the highest down, e.g. `AKT2`.
+fn holding(hand: &Cards, suit: Suit) -> String {
+    let mut ranks: Vec<u8> = evaluation::suit_cards(hand, suit)
+        .map(|card| card.rank())
+        .collect();
+    ranks.sort_unstable_by(|a, b| b.cmp(a));
+    ranks
+        .into_iter()
+        .map(|rank| match rank {
+            14 => 'A',
+            13 => 'K',
+            12 => 'Q',
+            11 => 'J',
+            10 => 'T',
+            _ => (b'0' + rank) as char,
+        })
+        .collect()
+}
+
+/// Reads a rank written as in [`holding`].
+fn rank_from_char(rank: char) -> Option<u8> {
+    match rank.to_ascii_uppercase() {
+        'A' => Some(14),
+        'K' => Some(13),
+        'Q' => Some(12),
+        'J' => Some(11),
+        'T' => Some(10),
+        '2'..='9' => Some(rank as u8 - b'0'),
+        _ => None,
+    }
+}
+
+/// Displays the board as a classic hand diagram, headed by the board number, the dealer and the vulnerability.
+///
+/// North is at the top, South at the bottom, West on the left and East on the right. Each hand lists its suits from spades down to clubs.
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const INDENT: &str = "            ";
+
+        let vulnerability = match self.vulnerability() {
+            Vulnerability::NONE => "None",
+            Vulnerability::NS => "N-S",
+            Vulnerability::EW => "E-W",
+            Vulnerability::ALL => "All",
+        };
+        writeln!(f, "Board {}", self.number)?;
+        writeln!(f, "Dealer: {:?}", self.dealer())?;
+        writeln!(f, "Vulnerable: {}", vulnerability)?;
+
+        for &suit in SUITS.iter() {
+            writeln!(f, "{}{}", INDENT, holding_line(&self.north, suit))?;
+        }
+        for &suit in SUITS.iter() {
+            let west = holding_line(&self.west, suit);
+            writeln!(f, "{:<24}{}", west, holding_line(&self.east, suit))?;
+        }
+        for &suit in SUITS.iter() {
+            writeln!(f, "{}{}", INDENT, holding_line(&self.south, suit))?;
+        }
+        Ok(())
+    }
+}
+
+/// The four suits in display order, from the highest-ranking down: spades, hearts, diamonds and clubs.
+///
+/// Hands are written and shown in this order, in PBN and in hand diagrams alike. Iterate it in reverse to go from clubs up.
+pub(crate) const SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+/// These are possible errors arising from reading a deal.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The deal doesn't start with the seat of the first hand: `N:`, `E:`, `S:` or `W:`
+    InvalidFirstHand,
+
+    /// The deal doesn't hold four hands of four suits each
+    WrongFormat,
+
+    /// This character isn't a rank
+    InvalidRank(char),
+
+    /// This card was dealt more than once
+    DuplicateCard(Card),
+
+    /// A player was expected to hold exactly 13 cards, but held this many
+    WrongHandSize(BridgeDirection, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidFirstHand => {
+                f.write_str("the deal must start with N:, E:, S: or W:")
+            }
+            ParseError::WrongFormat => f.write_str("the deal must hold four hands of four suits"),
+            ParseError::InvalidRank(rank) => write!(f, "invalid rank '{}'", rank),
+            ParseError::DuplicateCard(card) => write!(f, "{:?} was dealt more than once", card),
+            ParseError::WrongHandSize(who, len) => {
+                write!(f, "{:?} holds {} cards instead of 13", who, len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The number of boards dealt when looking for a deal with specific features, before giving up.
+pub const MAX_DEAL_ATTEMPTS: usize = 100_000;
+
+/// Themes for generating teaching deals, see [`Board::themed_deal`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum DealTheme {
+    /// The player holds a strong notrump opening: 15 to 17 HCP and a balanced hand
+    StrongNoTrump(BridgeDirection),
+
+    /// The player holds a preempt: 5 to 10 HCP and a seven-card suit
+    Preempt(BridgeDirection),
+
+    /// North and South hold at least 33 HCP between them
+    SlamForNS,
+}
+
+impl DealTheme {
+    fn matches(&self, board: &Board) -> bool {
+        match *self {
+            DealTheme::StrongNoTrump(who) => {
+                let hand = board.hand(who);
+                (15..=17).contains(&evaluation::hcp(&hand))
+                    && evaluation::hand_type(&hand) == evaluation::HandType::Balanced
+            }
+            DealTheme::Preempt(who) => {
+                let hand = board.hand(who);
+                (5..=10).contains(&evaluation::hcp(&hand)) && evaluation::longest_suit(&hand).1 == 7
+            }
+            DealTheme::SlamForNS => {
+                evaluation::hcp(&board.north) + evaluation::hcp(&board.south) >= 33
+            }
+        }
+    }
+}
+
+/// These are possible errors arising from dealing a board.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DealError {
+    /// A hand was expected to hold exactly 13 cards, but held this many
+    WrongHandSize(usize),
+
+    /// No suitable deal was found within [`MAX_DEAL_ATTEMPTS`] boards
+    TooManyAttempts,
+
+    /// Boards are numbered from 1, so there is no board 0
+    InvalidBoardNumber,
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DealError::WrongHandSize(len) => write!(f, "a hand holds {} cards instead of 13", len),
+            DealError::TooManyAttempts => write!(
+                f,
+                "no suitable deal found in {} attempts",
+                MAX_DEAL_ATTEMPTS
+            ),
+            DealError::InvalidBoardNumber => f.write_str("boards are numbered from 1"),
         }
     }
 }
 
+impl std::error::Error for DealError {}
+
 /// Represents a specific position at a bridge table.
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub enum BridgeDirection {
@@ -102,8 +584,23 @@ pub enum BridgeDirection {
 }
 
 impl BridgeDirection {
+    /// Returns the four players, in clockwise order starting with North.
+    pub const fn all() -> [BridgeDirection; 4] {
+        [
+            BridgeDirection::N,
+            BridgeDirection::E,
+            BridgeDirection::S,
+            BridgeDirection::W,
+        ]
+    }
+
+    /// Iterates over the four players, in the same order as [`all`](BridgeDirection::all).
+    pub fn iter() -> impl Iterator<Item = BridgeDirection> {
+        turns(BridgeDirection::N).take(4)
+    }
+
     /// Returns the partner of a specific player.
-    fn partner(&self) -> BridgeDirection {
+    pub fn partner(&self) -> BridgeDirection {
         match self {
             BridgeDirection::N => BridgeDirection::S,
             BridgeDirection::E => BridgeDirection::W,
@@ -111,6 +608,70 @@ impl BridgeDirection {
             BridgeDirection::W => BridgeDirection::E,
         }
     }
+
+    /// Returns the left hand opponent, i.e. the next player in clockwise order.
+    pub fn lho(&self) -> BridgeDirection {
+        match self {
+            BridgeDirection::N => BridgeDirection::E,
+            BridgeDirection::E => BridgeDirection::S,
+            BridgeDirection::S => BridgeDirection::W,
+            BridgeDirection::W => BridgeDirection::N,
+        }
+    }
+
+    /// Returns the right hand opponent, i.e. the previous player in clockwise order.
+    pub fn rho(&self) -> BridgeDirection {
+        self.lho().partner()
+    }
+
+    /// Returns true if both players belong to the same side. A player is not their own partner.
+    pub fn is_partner_of(&self, other: BridgeDirection) -> bool {
+        self.partner() == other
+    }
+
+    /// Returns true if the players belong to different sides.
+    pub fn is_opponent_of(&self, other: BridgeDirection) -> bool {
+        Partnership::of(*self) != Partnership::of(other)
+    }
+}
+
+/// Represents one of the two partnerships at a bridge table.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Partnership {
+    /// North and South
+    NS,
+    /// East and West
+    EW,
+}
+
+impl Partnership {
+    /// Returns the side a specific player belongs to.
+    ///
+    /// ```
+    /// use bridge_backend::{BridgeDirection, Partnership};
+    ///
+    /// assert_eq!(Partnership::of(BridgeDirection::S), Partnership::NS);
+    /// assert_eq!(Partnership::of(BridgeDirection::E), Partnership::EW);
+    /// ```
+    pub fn of(who: BridgeDirection) -> Partnership {
+        match who {
+            BridgeDirection::N | BridgeDirection::S => Partnership::NS,
+            BridgeDirection::E | BridgeDirection::W => Partnership::EW,
+        }
+    }
+
+    /// Returns the other side, i.e. this side's opponents.
+    pub fn opponents(self) -> Partnership {
+        match self {
+            Partnership::NS => Partnership::EW,
+            Partnership::EW => Partnership::NS,
+        }
+    }
+
+    /// Returns true if the player belongs to this side.
+    pub fn contains(self, who: BridgeDirection) -> bool {
+        Partnership::of(who) == self
+    }
 }
 
 /// An iterator that returns the natural turns of a bridge game.
@@ -125,12 +686,7 @@ impl Iterator for Turns {
 
     fn next(&mut self) -> Option<Self::Item> {
         let res = self.last;
-        self.last = match self.last {
-            BridgeDirection::N => BridgeDirection::E,
-            BridgeDirection::E => BridgeDirection::S,
-            BridgeDirection::S => BridgeDirection::W,
-            BridgeDirection::W => BridgeDirection::N,
-        };
+        self.last = self.last.lho();
         Some(res)
     }
 }
@@ -155,10 +711,50 @@ pub fn turns(dealer: BridgeDirection) -> Turns {
     Turns { last: dealer }
 }
 
+/// Returns one full round of the table: the four players in clockwise order, starting with `from`.
+///
+/// This is a fixed-size alternative to [`turns()`] for the common case of a single round.
+///
+/// # Example
+/// ```
+/// use bridge_backend::{seat_order, BridgeDirection::*};
+///
+/// assert_eq!(seat_order(S), [S, W, N, E]);
+/// ```
+pub fn seat_order(from: BridgeDirection) -> [BridgeDirection; 4] {
+    let second = from.lho();
+    [from, second, second.lho(), from.rho()]
+}
+
+/// Returns the vulnerability of a board number, without dealing the board. See [`Board::vulnerability`].
+///
+/// The vulnerability repeats every 16 boards, so board 16 and its multiples (remainder 0) are EW vulnerable, like board 16 of the chart.
+/// Boards are numbered from 1: board 0 isn't a real board, see [`Board::try_new`].
+pub fn vulnerability_for(number: usize) -> Vulnerability {
+    match number % 16 {
+        1 | 8 | 11 | 14 => Vulnerability::NONE,
+        2 | 5 | 12 | 15 => Vulnerability::NS,
+        3 | 6 | 9 | 0 => Vulnerability::EW,
+        _ => Vulnerability::ALL,
+    }
+}
+
+/// Returns the dealer of a board number, without dealing the board. See [`Board::dealer`].
+///
+/// The dealer moves one seat clockwise from each board to the next, starting with North on board 1, so West deals boards 4, 8, 12, 16 and so on.
+pub fn dealer_for(number: usize) -> BridgeDirection {
+    match number % 4 {
+        1 => BridgeDirection::N,
+        2 => BridgeDirection::E,
+        3 => BridgeDirection::S,
+        _ => BridgeDirection::W,
+    }
+}
+
 /// A struct which represents a bridge board vulnerability.
 ///
 /// It is created by the [`vulnerability`](method@Board::vulnerability) method on a [Board].
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub enum Vulnerability {
     /// North-South vulnerable
     NS,
@@ -176,12 +772,31 @@ impl Vulnerability {
     /// Utility function to test the vulnerability of a specific player.
     pub fn is_vulnerable(self, who: BridgeDirection) -> bool {
         match self {
-            Vulnerability::NS => [BridgeDirection::N, BridgeDirection::S].contains(&who),
-            Vulnerability::EW => [BridgeDirection::E, BridgeDirection::W].contains(&who),
+            Vulnerability::NS => Partnership::NS.contains(who),
+            Vulnerability::EW => Partnership::EW.contains(who),
             Vulnerability::ALL => true,
             Vulnerability::NONE => false,
         }
     }
+
+    /// Returns true if a whole side is vulnerable.
+    pub fn is_side_vulnerable(self, side: Partnership) -> bool {
+        match self {
+            Vulnerability::NS => side == Partnership::NS,
+            Vulnerability::EW => side == Partnership::EW,
+            Vulnerability::ALL => true,
+            Vulnerability::NONE => false,
+        }
+    }
+
+    /// Lists the vulnerable sides, North-South first.
+    pub fn vulnerable_sides(self) -> Vec<Partnership> {
+        [Partnership::NS, Partnership::EW]
+            .iter()
+            .copied()
+            .filter(|&side| self.is_side_vulnerable(side))
+            .collect()
+    }
 }
 
 /// Represents the state of a bridge board.
@@ -191,64 +806,528 @@ pub struct BoardPlay {
     table_number: usize,
     contract: Option<Contract>,
     tricks_taken: usize,
+    revoke_policy: RevokePolicy,
 }
 
-impl BoardPlay {
-    /// Creates a new `BoardPlay` with default values. Todo: Replace with the `Default` trait.
-    pub fn new() -> Self {
+impl Default for BoardPlay {
+    /// Starts the first board at table 0, before the auction.
+    fn default() -> Self {
         Self {
             board: Board::first(),
             state: Default::default(),
             table_number: 0,
             contract: None,
             tricks_taken: 0,
+            revoke_policy: RevokePolicy::Reject,
         }
     }
-
-    /// Calculates the score for the board.
-    ///
-    /// The score is returned from the perspective of North-South, in accordance to the real-world standard set by other software.
-    ///
-    /// Returns `None` when the board is not completed yet.
-    pub fn score(self) -> Option<i32> {
-        match self.state {
-            BoardState::Completed => Some(
-                self.contract?
-                    .get_score_for_tricks(self.tricks_taken, self.board.vulnerability()),
-            ),
-            _ => None,
-        }
-    }
-}
-
-enum BoardState {
-    NotStarted,
-    Bidding(Auction),
-    OnLead(Auction),
-    Playing(Auction, Contract, Cardplay),
-    Completed,
 }
 
-impl Default for BoardState {
-    fn default() -> Self {
-        Self::NotStarted
+impl BoardPlay {
+    /// Creates a new `BoardPlay` with default values, see [`BoardPlay::default`].
+    pub fn new() -> Self {
+        Default::default()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::Board;
+    /// Sets the table where the board is played, e.g. `BoardPlay::new().at_table(2)`.
+    ///
+    /// In team events, the same board is played at several tables, and the results are compared between them.
+    pub fn at_table(mut self, table: usize) -> Self {
+        self.table_number = table;
+        self
+    }
 
-    #[test]
-    fn new_board() {
-        let board = Board::first();
-        assert_eq!(board.number, 1);
+    /// Sets how the play deals with a player who doesn't follow suit, e.g. `BoardPlay::new().with_revoke_policy(RevokePolicy::Track)`.
+    ///
+    /// Revokes are refused by default, see [`RevokePolicy`]. Tracked revokes are penalized once the play is over, which is reflected in the [`score`](BoardPlay::score).
+    pub fn with_revoke_policy(mut self, revoke_policy: RevokePolicy) -> Self {
+        self.revoke_policy = revoke_policy;
+        self
+    }
 
-        let board = Board::new(7);
-        assert_eq!(board.number, 7);
+    /// Returns the table where the board is played, 0 unless set with [`at_table`](BoardPlay::at_table).
+    pub fn table_number(&self) -> usize {
+        self.table_number
     }
 
-    #[test]
+    /// Rebuilds a board from a record of its bids and the cards played, in order, e.g. when importing historical hands.
+    ///
+    /// Each card is played by the player whose turn it is. The record may stop at any point, e.g. after the auction; the board is completed once the auction is passed out or all 13 tricks are played.
+    ///
+    /// Returns an error with the position of the first bid or card that can't be made.
+    pub fn replay(board: Board, bids: &[Bid], plays: &[Card]) -> Result<Self, ReplayError> {
+        let mut board_play = Self {
+            board,
+            ..Default::default()
+        };
+        board_play
+            .start_auction()
+            .expect("A new board hasn't started yet");
+
+        for (index, &bid) in bids.iter().enumerate() {
+            board_play
+                .bid(bid)
+                .map_err(|error| ReplayError::Bid(index, error))?;
+        }
+        for (index, &card) in plays.iter().enumerate() {
+            // When no one is on turn, the board isn't being played, and any seat gets the same error
+            let player = board_play.player_on_turn().unwrap_or(BridgeDirection::N);
+            board_play
+                .play_card(player, card)
+                .map_err(|error| ReplayError::Play(index, error))?;
+        }
+        Ok(board_play)
+    }
+
+    /// Starts the auction, with the board's dealer as the first to bid.
+    ///
+    /// Returns an error unless the board hasn't started yet.
+    pub fn start_auction(&mut self) -> Result<(), StateError> {
+        match self.state {
+            BoardState::NotStarted => {
+                self.state = BoardState::Bidding(Auction::new(self.board.dealer()));
+                Ok(())
+            }
+            BoardState::Bidding(_) => Err(StateError::AuctionInProgress),
+            BoardState::OnLead(..) | BoardState::Playing(..) => Err(StateError::AuctionCompleted),
+            BoardState::Completed(_) => Err(StateError::Completed),
+        }
+    }
+
+    /// Makes the next bid of the auction.
+    ///
+    /// Once the auction is over, the board moves on to the opening lead, or straight to its completed state when it was passed out.
+    ///
+    /// Returns an error if the auction isn't in progress, or if the bid itself is illegal.
+    pub fn bid(&mut self, bid: Bid) -> Result<(), StateError> {
+        let auction = match &mut self.state {
+            BoardState::Bidding(auction) => auction,
+            BoardState::NotStarted => return Err(StateError::NotStarted),
+            BoardState::OnLead(..) | BoardState::Playing(..) => {
+                return Err(StateError::AuctionCompleted)
+            }
+            BoardState::Completed(_) => return Err(StateError::Completed),
+        };
+        auction.bid(bid).map_err(StateError::Bid)?;
+
+        match auction.contract() {
+            None => {}
+            Some(Contract::PassedOut) => {
+                self.contract = Some(Contract::PassedOut);
+                self.complete();
+            }
+            Some(contract) => {
+                let bid_contract = auction
+                    .bid_contract()
+                    .expect("Auctions that aren't passed out have a contract");
+                let cardplay = Cardplay::start_with_revoke_policy(
+                    &self.board,
+                    bid_contract,
+                    self.revoke_policy,
+                );
+                self.contract = Some(contract);
+                self.state = BoardState::OnLead(std::mem::take(auction), cardplay);
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays a card, see [`Cardplay::play_card`].
+    ///
+    /// The opening lead starts the play, and the board is completed once the 13th trick has been played.
+    ///
+    /// Returns an error if the board isn't on lead or being played, or if the card can't be played.
+    pub fn play_card(&mut self, player: BridgeDirection, card: Card) -> Result<(), StateError> {
+        let cardplay = self.cardplay_mut()?;
+        cardplay.play_card(player, card).map_err(StateError::Play)?;
+        let completed = cardplay.is_completed();
+        let tricks_taken = cardplay.tricks_for_declarer();
+
+        self.start_play();
+        if completed {
+            self.tricks_taken = tricks_taken;
+            self.complete();
+        }
+        Ok(())
+    }
+
+    /// Ends the play with a claim, see [`Cardplay::claim`]: the claimer's side takes `tricks` of the tricks left to play.
+    ///
+    /// The board is completed straight away, with the tricks declarer won including those conceded by the claim.
+    ///
+    /// Returns an error if the board isn't on lead or being played, or if the claim can't be made.
+    pub fn claim(&mut self, claimer: BridgeDirection, tricks: usize) -> Result<(), StateError> {
+        let cardplay = self.cardplay_mut()?;
+        cardplay.claim(claimer, tricks).map_err(StateError::Play)?;
+        self.tricks_taken = cardplay.tricks_for_declarer();
+
+        self.start_play();
+        self.complete();
+        Ok(())
+    }
+
+    /// Returns the play of the board, e.g. to find out which cards a player may play, see [`Cardplay`].
+    ///
+    /// The play starts as soon as the auction is over, so this returns `None` only before then, or if the auction was passed out. Once the board is completed, this is the play it was completed from.
+    ///
+    /// ```
+    /// use bridge_backend::auction::constants::*;
+    /// use bridge_backend::{Board, BoardPlay, BridgeDirection, ContractFate, EndgameStatus};
+    /// use bridge_deck::Card;
+    ///
+    /// // North holds the spades, East the hearts, South the diamonds and West the clubs
+    /// let board = Board::from_big_deal_number(1, 1);
+    /// let east = board.east;
+    /// let bids = [SEVEN_SPADES, PASS, PASS, PASS];
+    /// let mut board_play = BoardPlay::replay(board, &bids, &[]).unwrap();
+    /// assert_eq!(board_play.cardplay().unwrap().legal_plays(BridgeDirection::E), east);
+    ///
+    /// board_play.play_card(BridgeDirection::E, Card::HA).unwrap();
+    /// let cardplay = board_play.cardplay().unwrap();
+    /// assert_eq!(cardplay.dummy(), Some(BridgeDirection::S));
+    /// assert!(!cardplay.is_legal_play(BridgeDirection::E, Card::HK));
+    ///
+    /// for &(player, card) in &[(BridgeDirection::S, Card::DA), (BridgeDirection::W, Card::CA)] {
+    ///     board_play.play_card(player, card).unwrap();
+    /// }
+    /// board_play.play_card(BridgeDirection::N, Card::SA).unwrap();
+    /// assert_eq!(
+    ///     board_play.cardplay().unwrap().endgame_status(),
+    ///     Some(EndgameStatus {
+    ///         tricks_remaining: 12,
+    ///         tricks_needed: 12,
+    ///         fate: ContractFate::Open,
+    ///     })
+    /// );
+    /// ```
+    pub fn cardplay(&self) -> Option<&Cardplay> {
+        self.state.cardplay()
+    }
+
+    /// Returns the play of a board that is on lead or being played, or the matching error otherwise.
+    fn cardplay_mut(&mut self) -> Result<&mut Cardplay, StateError> {
+        match &mut self.state {
+            BoardState::NotStarted => Err(StateError::NotStarted),
+            BoardState::Bidding(_) => Err(StateError::AuctionInProgress),
+            BoardState::Completed(_) => Err(StateError::Completed),
+            BoardState::OnLead(_, cardplay) | BoardState::Playing(_, _, cardplay) => Ok(cardplay),
+        }
+    }
+
+    /// Moves a board on lead to being played, once the opening lead was made or the play was claimed.
+    fn start_play(&mut self) {
+        self.state = match std::mem::take(&mut self.state) {
+            BoardState::OnLead(auction, cardplay) => {
+                let contract = auction.contract().expect("Boards on lead have been bid");
+                BoardState::Playing(auction, contract, cardplay)
+            }
+            state => state,
+        };
+    }
+
+    /// Calculates the score for the board.
+    ///
+    /// The score is returned from the perspective of North-South, in accordance to the real-world standard set by other software.
+    ///
+    /// Returns `None` when the board is not completed yet.
+    pub fn score(&self) -> Option<i32> {
+        match self.state {
+            BoardState::Completed(_) => {
+                let contract = self.contract?;
+                Some(contract.get_score_for_tricks_from(
+                    self.tricks_taken,
+                    self.board.vulnerability(),
+                    Partnership::NS,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Compares this board's result with the North-South score obtained at another table, and returns the swing in IMPs.
+    ///
+    /// The swing is from the perspective of North-South at this table. Returns `None` when the board is not completed yet.
+    pub fn imp_vs(&self, reference_ns_score: i32) -> Option<i32> {
+        Some(scoring::imps_from_difference(
+            self.score()? - reference_ns_score,
+        ))
+    }
+
+    /// Returns the player who must make the opening lead, i.e. the declarer's left-hand opponent.
+    ///
+    /// Returns `None` unless the auction is over and the opening lead hasn't been made yet.
+    pub fn opening_leader(&self) -> Option<BridgeDirection> {
+        match &self.state {
+            BoardState::OnLead(_, cardplay) => Some(cardplay.whose_turn_is_it()),
+            _ => None,
+        }
+    }
+
+    /// Returns the player who must play the next card, or `None` when the board isn't on lead or being played.
+    fn player_on_turn(&self) -> Option<BridgeDirection> {
+        match &self.state {
+            BoardState::Playing(_, _, cardplay) => Some(cardplay.whose_turn_is_it()),
+            _ => self.opening_leader(),
+        }
+    }
+
+    /// Returns the partnership playing the contract.
+    ///
+    /// Returns `None` when no contract has been set, or the board was passed out.
+    pub fn declaring_side(&self) -> Option<Partnership> {
+        match self.contract? {
+            Contract::PassedOut => None,
+            Contract::BidContract(contract) => Some(Partnership::of(contract.declarer)),
+        }
+    }
+
+    /// Returns how many more tricks the declaring side needs to bring home a game bonus.
+    ///
+    /// While the board is being played, this counts the tricks declarer has won so far. The bonus depends on the trick points of the contract, so a doubled partscore such as 2♠x, worth 120 below the line, earns it when made. A contract worth less than 100 trick points can't earn it however many tricks are taken, so it always reports 0.
+    ///
+    /// Returns `None` when no contract has been set, or the board was passed out.
+    pub fn tricks_until_game_bonus(&self) -> Option<usize> {
+        match self.contract.as_ref()? {
+            Contract::PassedOut => None,
+            Contract::BidContract(contract) if contract.trick_points() >= 100 => Some(
+                contract
+                    .tricks_needed()
+                    .saturating_sub(self.declarer_tricks()),
+            ),
+            Contract::BidContract(_) => Some(0),
+        }
+    }
+
+    /// Returns the state of a board whose play was taken back: still being played, or back on lead if no card is left.
+    fn resume_play(auction: Auction, contract: Contract, cardplay: Cardplay) -> BoardState {
+        if cardplay.opening_lead().is_none() {
+            BoardState::OnLead(auction, cardplay)
+        } else {
+            BoardState::Playing(auction, contract, cardplay)
+        }
+    }
+
+    /// Returns the tricks won by declarer: so far while the board is being played, and in total once it's completed.
+    fn declarer_tricks(&self) -> usize {
+        match &self.state {
+            BoardState::Playing(_, _, cardplay) => cardplay.tricks_for_declarer(),
+            _ => self.tricks_taken,
+        }
+    }
+
+    /// Prints the board as a hand record, the way clubs hand them out after a session.
+    ///
+    /// This is the board's diagram (see the [`Display`](fmt::Display) implementation of [`Board`]), followed by the contract once it's known, and by the result and the North-South score once the board is completed.
+    pub fn hand_record(&self) -> String {
+        let mut record = self.board.to_string();
+        if let Some(contract) = self.contract {
+            record.push_str(&format!("Contract: {}\n", contract));
+        }
+        if let Some(score) = self.score() {
+            if let Some(Contract::BidContract(_)) = self.contract {
+                record.push_str(&format!("Tricks: {}\n", self.tricks_taken));
+            }
+            record.push_str(&format!("Score (N-S): {}\n", score));
+        }
+        record
+    }
+
+    /// Records the final contract and the number of tricks taken by declarer, skipping the card play.
+    ///
+    /// This suits scoring apps where only the result is entered. The board moves straight to its completed state, so [`score`](BoardPlay::score) becomes available.
+    ///
+    /// Returns an error unless the auction has ended, i.e. the board is on lead or being played.
+    pub fn set_result(
+        &mut self,
+        contract: Contract,
+        declarer_tricks: usize,
+    ) -> Result<(), StateError> {
+        match self.state {
+            BoardState::NotStarted => Err(StateError::NotStarted),
+            BoardState::Bidding(_) => Err(StateError::AuctionInProgress),
+            BoardState::Completed(_) => Err(StateError::Completed),
+            BoardState::OnLead(..) | BoardState::Playing(..) => {
+                self.contract = Some(contract);
+                self.tricks_taken = declarer_tricks;
+                self.complete();
+                Ok(())
+            }
+        }
+    }
+
+    /// Moves the board to its completed state, keeping the state it was completed from so it can be undone.
+    fn complete(&mut self) {
+        let previous = std::mem::take(&mut self.state);
+        self.state = BoardState::Completed(Box::new(previous));
+    }
+
+    /// Takes back the last action on the board, whatever the phase: the last card played, or the last bid.
+    ///
+    /// Taking back the card that ended the play, or the pass that ended the auction, reopens the board. Taking back the opening lead puts the board back on lead, and taking back the last bid of the auction reopens the auction. A result entered with [`set_result`](BoardPlay::set_result) or a claim is withdrawn on its own.
+    ///
+    /// Returns false if there is nothing to undo, i.e. the board hasn't started or no bid was made yet.
+    pub fn undo(&mut self) -> bool {
+        let (state, undone) = match std::mem::take(&mut self.state) {
+            BoardState::NotStarted => (BoardState::NotStarted, false),
+            BoardState::Bidding(mut auction) => {
+                let undone = auction.undo_last_bid().is_some();
+                (BoardState::Bidding(auction), undone)
+            }
+            BoardState::OnLead(mut auction, _) => {
+                auction.undo_last_bid();
+                (BoardState::Bidding(auction), true)
+            }
+            BoardState::Playing(auction, contract, mut cardplay) => {
+                cardplay.undo_last_play();
+                (Self::resume_play(auction, contract, cardplay), true)
+            }
+            BoardState::Completed(previous) => match *previous {
+                // The auction was passed out, or the play was completed by a card or a claim
+                BoardState::Bidding(mut auction) => {
+                    auction.undo_last_bid();
+                    (BoardState::Bidding(auction), true)
+                }
+                BoardState::Playing(auction, contract, mut cardplay) if cardplay.is_completed() => {
+                    cardplay.undo_last_play();
+                    (Self::resume_play(auction, contract, cardplay), true)
+                }
+                // The result was entered with set_result
+                previous => (previous, true),
+            },
+        };
+
+        self.contract = match &state {
+            BoardState::OnLead(auction, _) | BoardState::Playing(auction, ..) => auction.contract(),
+            _ => None,
+        };
+        self.tricks_taken = 0;
+        self.state = state;
+        undone
+    }
+}
+
+/// These are possible errors arising from driving a [`BoardPlay`] through its phases.
+#[derive(Debug, Eq, PartialEq)]
+pub enum StateError {
+    /// The board hasn't started yet
+    NotStarted,
+
+    /// The auction is still in progress
+    AuctionInProgress,
+
+    /// The auction is already over
+    AuctionCompleted,
+
+    /// The board has already been completed
+    Completed,
+
+    /// The bid was rejected by the auction
+    Bid(auction::Error),
+
+    /// The card can't be played
+    Play(PlayError),
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::NotStarted => f.write_str("the board hasn't started yet"),
+            StateError::AuctionInProgress => f.write_str("the auction is still in progress"),
+            StateError::AuctionCompleted => f.write_str("the auction is already over"),
+            StateError::Completed => f.write_str("the board has already been completed"),
+            StateError::Bid(error) => write!(f, "bid rejected: {}", error),
+            StateError::Play(error) => write!(f, "card rejected: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for StateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StateError::Bid(error) => Some(error),
+            StateError::Play(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// These are possible errors arising from replaying a board, see [`BoardPlay::replay`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReplayError {
+    /// The bid at this position in the record was rejected
+    Bid(usize, StateError),
+
+    /// The card at this position in the record couldn't be played
+    Play(usize, StateError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Bid(index, error) => write!(f, "bid #{}: {}", index + 1, error),
+            ReplayError::Play(index, error) => write!(f, "card #{}: {}", index + 1, error),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReplayError::Bid(_, error) | ReplayError::Play(_, error) => Some(error),
+        }
+    }
+}
+
+enum BoardState {
+    NotStarted,
+    Bidding(Auction),
+    /// The auction is over, and the play has started without any card played yet
+    OnLead(Auction, Cardplay),
+    Playing(Auction, Contract, Cardplay),
+    /// The board is over, along with the state it was completed from
+    Completed(Box<BoardState>),
+}
+
+impl BoardState {
+    fn cardplay(&self) -> Option<&Cardplay> {
+        match self {
+            BoardState::OnLead(_, cardplay) | BoardState::Playing(_, _, cardplay) => Some(cardplay),
+            BoardState::Completed(previous) => previous.cardplay(),
+            BoardState::NotStarted | BoardState::Bidding(_) => None,
+        }
+    }
+}
+
+impl Default for BoardState {
+    fn default() -> Self {
+        Self::NotStarted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auction::{self, constants::*};
+    use crate::contract::{BidContract, Contract, Modifier};
+    use crate::deal::DEAL_COUNT;
+    use crate::evaluation::{hand_type, hcp, longest_suit, HandType};
+    use crate::{dealer_for, vulnerability_for, Vulnerability};
+    use crate::{
+        turns, Auction, Board, BoardPlay, BoardState, BridgeDirection, Cardplay, DealError,
+        DealTheme, ParseError, Partnership, PlayError, ReplayError, RevokePolicy, StateError,
+    };
+    use bridge_deck::{Card, Cards};
+    use std::convert::TryInto;
+
+    #[test]
+    fn new_board() {
+        let board = Board::first();
+        assert_eq!(board.number, 1);
+
+        let board = Board::new(7);
+        assert_eq!(board.number, 7);
+    }
+
+    #[test]
     fn all_cards_should_exist() {
         let board = Board::first();
         let cards = board
@@ -267,4 +1346,826 @@ mod tests {
         assert_eq!(board.south.len(), 13);
         assert_eq!(board.west.len(), 13);
     }
+
+    #[test]
+    fn big_deal_number_is_stable() {
+        // The first and last deals of the published numbering
+        let first = Board::from_big_deal_number(3, 1);
+        assert_eq!(first.number, 3);
+        assert_eq!(first.north, Cards::SPADES);
+        assert_eq!(first.east, Cards::HEARTS);
+        assert_eq!(first.south, Cards::DIAMONDS);
+        assert_eq!(first.west, Cards::CLUBS);
+
+        let last = Board::from_big_deal_number(3, DEAL_COUNT);
+        assert_eq!(last.north, Cards::CLUBS);
+        assert_eq!(last.east, Cards::DIAMONDS);
+        assert_eq!(last.south, Cards::HEARTS);
+        assert_eq!(last.west, Cards::SPADES);
+
+        assert_eq!(Board::from_big_deal_number(3, 0).west, Cards::SPADES);
+        assert_eq!(
+            Board::from_big_deal_number(3, DEAL_COUNT + 1).north,
+            Cards::SPADES
+        );
+
+        // The second deal only swaps the two of diamonds and the ace of clubs between South and West
+        let second = Board::from_big_deal_number(3, 2);
+        assert_eq!(second.north, Cards::SPADES);
+        assert!(second.south.contains(Card::CA));
+        assert!(second.west.contains(Card::D2));
+
+        let some_deal = Board::from_big_deal_number(3, 1_234_567_890_123_456_789);
+        let again = Board::from_big_deal_number(3, 1_234_567_890_123_456_789);
+        assert_eq!(some_deal.north, again.north);
+        assert_eq!(some_deal.east, again.east);
+        assert_eq!(some_deal.south, again.south);
+        assert_eq!(some_deal.west, again.west);
+    }
+
+    #[test]
+    fn seeded_deal() {
+        let board = Board::from_seed(7, 2021);
+        let again = Board::from_seed(7, 2021);
+        assert_eq!(board.number, 7);
+        assert_eq!(board.north, again.north);
+        assert_eq!(board.east, again.east);
+        assert_eq!(board.south, again.south);
+        assert_eq!(board.west, again.west);
+        assert_eq!(
+            board
+                .north
+                .union(board.east)
+                .union(board.south)
+                .union(board.west),
+            Cards::ALL
+        );
+
+        assert_ne!(Board::from_seed(7, 2022).north, board.north);
+    }
+
+    #[test]
+    fn deal_around_known_hand() {
+        let diamonds = suited_board().south;
+        let board = Board::deal_around(5, BridgeDirection::S, diamonds).unwrap();
+        assert_eq!(board.number, 5);
+        assert_eq!(board.south, diamonds);
+        assert_eq!(board.north.len(), 13);
+        assert_eq!(board.east.len(), 13);
+        assert_eq!(board.west.len(), 13);
+        assert_eq!(
+            board
+                .north
+                .union(board.east)
+                .union(board.south)
+                .union(board.west),
+            Cards::ALL
+        );
+
+        let mut short_hand = diamonds;
+        short_hand.remove(Card::D2);
+        assert_eq!(
+            Board::deal_around(5, BridgeDirection::S, short_hand).err(),
+            Some(DealError::WrongHandSize(12))
+        );
+    }
+
+    #[test]
+    fn pbn() {
+        let deal = "N:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.85";
+        let board = Board::from_pbn(3, deal).unwrap();
+        assert_eq!(board.number, 3);
+        assert!(board.north.contains(Card::S6));
+        assert!(board.east.contains(Card::DA));
+        assert!(board.south.contains(Card::C4));
+        assert!(board.west.contains(Card::HT));
+        assert_eq!(board.to_pbn(), deal);
+
+        let voids = suited_board();
+        assert_eq!(
+            voids.to_pbn(),
+            "N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432"
+        );
+        let again = Board::from_pbn(1, &voids.to_pbn()).unwrap();
+        assert_eq!(again.west, voids.west);
+    }
+
+    #[test]
+    fn pbn_errors() {
+        let deal = |text| Board::from_pbn(1, text).err();
+        assert_eq!(
+            deal("X:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.85"),
+            Some(ParseError::InvalidFirstHand)
+        );
+        assert_eq!(
+            deal("N:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764"),
+            Some(ParseError::WrongFormat)
+        );
+        assert_eq!(
+            deal("N:A643.K87.T87 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.85"),
+            Some(ParseError::WrongFormat)
+        );
+        assert_eq!(
+            deal("N:A643.K87.T87.AQ1 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.85"),
+            Some(ParseError::InvalidRank('1'))
+        );
+        assert_eq!(
+            deal("N:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K9764 KQT2.AT.J6542.83"),
+            Some(ParseError::DuplicateCard(Card::C3))
+        );
+        assert_eq!(
+            deal("N:A643.K87.T87.AQ3 98.QJ6432.AK.JT2 J75.95.Q93.K976 KQT2.AT.J6542.854"),
+            Some(ParseError::WrongHandSize(BridgeDirection::S, 12))
+        );
+    }
+
+    #[test]
+    fn hcp_of_full_board() {
+        for number in 1..=8 {
+            let board = Board::new(number);
+            let total: u8 = turns(BridgeDirection::N)
+                .take(4)
+                .map(|who| board.hcp(who))
+                .sum();
+            assert_eq!(total, 40);
+        }
+        assert_eq!(suited_board().hcp(BridgeDirection::E), 10);
+    }
+
+    #[test]
+    fn mirror() {
+        let board = Board::from_big_deal_number(9, 12345);
+        let mirrored = board.mirror();
+        assert_eq!(mirrored.number, 9);
+        assert_eq!(mirrored.east, board.north);
+        assert_eq!(mirrored.west, board.south);
+
+        let back = mirrored.mirror();
+        assert_eq!(back.number, board.number);
+        assert_eq!(back.north, board.north);
+        assert_eq!(back.east, board.east);
+        assert_eq!(back.south, board.south);
+        assert_eq!(back.west, board.west);
+    }
+
+    #[test]
+    fn follows() {
+        let first = Board::first();
+        assert!(Board::new(2).follows(&first));
+        assert!(!Board::new(3).follows(&first));
+        assert!(!Board::new(1).follows(&first));
+        assert!(!first.follows(&Board::new(2)));
+        assert!(Board::new(17).follows(&Board::new(16)));
+    }
+
+    #[test]
+    fn themed_deal() {
+        let board = Board::themed_deal(DealTheme::StrongNoTrump(BridgeDirection::E), 4).unwrap();
+        assert_eq!(board.number, 4);
+        assert!((15..=17).contains(&hcp(&board.east)));
+        assert_eq!(hand_type(&board.east), HandType::Balanced);
+
+        let board = Board::themed_deal(DealTheme::Preempt(BridgeDirection::S), 4).unwrap();
+        assert!((5..=10).contains(&hcp(&board.south)));
+        assert_eq!(longest_suit(&board.south).1, 7);
+    }
+
+    #[test]
+    fn deal_constrained() {
+        let board = Board::deal_constrained(6, BridgeDirection::W, 15..=17).unwrap();
+        assert_eq!(board.number, 6);
+        assert!((15..=17).contains(&board.hcp(BridgeDirection::W)));
+
+        let board = Board::deal_constrained(6, BridgeDirection::N, 0..=3).unwrap();
+        assert!(board.hcp(BridgeDirection::N) <= 3);
+    }
+
+    #[test]
+    fn leader_longest_suit() {
+        let board = Board::first();
+        let contract = BidContract {
+            contract: "4h".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::N,
+        };
+        assert_eq!(board.hand(BridgeDirection::E), board.east);
+        assert_eq!(
+            board.leader_longest_suit(&contract),
+            longest_suit(&board.east)
+        );
+    }
+
+    #[test]
+    fn tricks_until_game_bonus() {
+        let mut board_play = BoardPlay::new();
+        assert_eq!(board_play.tricks_until_game_bonus(), None);
+
+        board_play.contract = Some(Contract::BidContract(BidContract {
+            contract: "4h".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        }));
+        board_play.tricks_taken = 7;
+        assert_eq!(board_play.tricks_until_game_bonus(), Some(3));
+        board_play.tricks_taken = 11;
+        assert_eq!(board_play.tricks_until_game_bonus(), Some(0));
+
+        board_play.contract = Some(Contract::BidContract(BidContract {
+            contract: "2s".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::S,
+        }));
+        board_play.tricks_taken = 4;
+        assert_eq!(board_play.tricks_until_game_bonus(), Some(8 - 4));
+
+        board_play.contract = Some(Contract::BidContract(BidContract {
+            contract: "2s".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::S,
+        }));
+        assert_eq!(board_play.tricks_until_game_bonus(), Some(0));
+
+        board_play.contract = Some(Contract::PassedOut);
+        assert_eq!(board_play.tricks_until_game_bonus(), None);
+
+        let plays = seven_spades_plays();
+        let bids = [FOUR_SPADES, PASS, PASS, PASS];
+        let tricks_until_game = |cards: usize| {
+            BoardPlay::replay(suited_board(), &bids, &plays[..cards])
+                .unwrap()
+                .tricks_until_game_bonus()
+        };
+        assert_eq!(tricks_until_game(0), Some(10));
+        assert_eq!(tricks_until_game(3), Some(10));
+        assert_eq!(tricks_until_game(4), Some(9));
+        assert_eq!(tricks_until_game(4 * 7), Some(3));
+        assert_eq!(tricks_until_game(4 * 13), Some(0));
+    }
+
+    #[test]
+    fn set_result() {
+        let contract = Contract::BidContract(BidContract {
+            contract: "4s".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::E,
+        });
+        // East declared, so North-South collect the penalty
+        let expected = -contract.get_score_for_tricks(9, Board::first().vulnerability());
+
+        let mut board_play = BoardPlay::new();
+        assert_eq!(
+            board_play.set_result(contract, 9),
+            Err(StateError::NotStarted)
+        );
+        board_play.state = BoardState::Bidding(Auction::new(BridgeDirection::N));
+        assert_eq!(
+            board_play.set_result(contract, 9),
+            Err(StateError::AuctionInProgress)
+        );
+
+        board_play.state = on_lead(&board_play.board);
+        assert_eq!(board_play.set_result(contract, 9), Ok(()));
+        assert_eq!(
+            board_play.set_result(contract, 10),
+            Err(StateError::Completed)
+        );
+        assert_eq!(board_play.score(), Some(expected));
+    }
+
+    #[test]
+    fn default_board_play() {
+        let board_play = BoardPlay::default();
+        assert_eq!(board_play.board.number, 1);
+        assert!(matches!(board_play.state, BoardState::NotStarted));
+        assert_eq!(board_play.table_number, 0);
+        assert_eq!(board_play.contract, None);
+        assert_eq!(board_play.tricks_taken, 0);
+    }
+
+    #[test]
+    fn table_number() {
+        assert_eq!(BoardPlay::new().table_number(), 0);
+        assert_eq!(BoardPlay::new().at_table(2).table_number(), 2);
+    }
+
+    #[test]
+    fn board_lifecycle() {
+        let mut board_play = BoardPlay::new();
+        board_play.board = suited_board();
+
+        assert_eq!(board_play.bid(SEVEN_SPADES), Err(StateError::NotStarted));
+        assert_eq!(board_play.start_auction(), Ok(()));
+        assert_eq!(
+            board_play.start_auction(),
+            Err(StateError::AuctionInProgress)
+        );
+        assert_eq!(
+            board_play.play_card(BridgeDirection::E, Card::HA),
+            Err(StateError::AuctionInProgress)
+        );
+
+        board_play.bid(SEVEN_SPADES).unwrap();
+        assert_eq!(
+            board_play.bid(SIX_SPADES),
+            Err(StateError::Bid(auction::Error::InsufficientBid))
+        );
+        for _ in 0..3 {
+            board_play.bid(PASS).unwrap();
+        }
+        assert!(matches!(board_play.state, BoardState::OnLead(..)));
+        assert_eq!(board_play.opening_leader(), Some(BridgeDirection::E));
+        assert_eq!(board_play.declaring_side(), Some(Partnership::NS));
+        assert_eq!(board_play.bid(PASS), Err(StateError::AuctionCompleted));
+
+        // A card out of turn leaves the board on lead
+        assert_eq!(
+            board_play.play_card(BridgeDirection::N, Card::SA),
+            Err(StateError::Play(PlayError::OutOfTurn))
+        );
+        assert!(matches!(board_play.state, BoardState::OnLead(..)));
+
+        // East leads a heart, which North ruffs. North then wins every trick.
+        let plays = seven_spades_plays();
+        board_play.play_card(BridgeDirection::E, plays[0]).unwrap();
+        assert!(matches!(board_play.state, BoardState::Playing(..)));
+        assert_eq!(board_play.opening_leader(), None);
+        let rest_of_first_trick = turns(BridgeDirection::S).take(3);
+        let seats = rest_of_first_trick.chain(turns(BridgeDirection::N).take(4 * 12));
+        for (who, &card) in seats.zip(&plays[1..]) {
+            assert_eq!(board_play.score(), None);
+            board_play.play_card(who, card).unwrap();
+        }
+        assert!(matches!(board_play.state, BoardState::Completed(_)));
+        assert_eq!(board_play.tricks_taken, 13);
+        assert_eq!(board_play.score(), Some(1510));
+        assert_eq!(
+            board_play.play_card(BridgeDirection::N, Card::SA),
+            Err(StateError::Completed)
+        );
+    }
+
+    #[test]
+    fn undo() {
+        let mut board_play = BoardPlay::new();
+        assert!(!board_play.undo());
+        board_play.start_auction().unwrap();
+        assert!(!board_play.undo());
+        assert!(matches!(board_play.state, BoardState::Bidding(_)));
+
+        // Taking back the pass that ended the auction reopens it
+        for _ in 0..4 {
+            board_play.bid(PASS).unwrap();
+        }
+        assert!(board_play.undo());
+        assert!(matches!(board_play.state, BoardState::Bidding(_)));
+        assert_eq!(board_play.contract, None);
+        assert_eq!(board_play.score(), None);
+
+        let board = suited_board;
+        let bids = [SEVEN_SPADES, PASS, PASS, PASS];
+        let plays = seven_spades_plays();
+
+        let mut on_lead = BoardPlay::replay(board(), &bids, &[]).unwrap();
+        assert!(on_lead.undo());
+        assert!(matches!(on_lead.state, BoardState::Bidding(_)));
+        assert_eq!(on_lead.contract, None);
+        on_lead.bid(PASS).unwrap();
+        assert!(matches!(on_lead.state, BoardState::OnLead(..)));
+
+        // Taking back the opening lead puts the board back on lead
+        let mut led = BoardPlay::replay(board(), &bids, &plays[..1]).unwrap();
+        assert!(led.undo());
+        assert!(matches!(led.state, BoardState::OnLead(..)));
+        assert_eq!(led.opening_leader(), Some(BridgeDirection::E));
+        assert_eq!(led.play_card(BridgeDirection::E, plays[0]), Ok(()));
+
+        // Taking back cards reopens the trick they completed
+        let mut playing = BoardPlay::replay(board(), &bids, &plays[..5]).unwrap();
+        assert!(playing.undo());
+        assert!(playing.undo());
+        assert!(matches!(playing.state, BoardState::Playing(..)));
+        assert_eq!(playing.player_on_turn(), Some(BridgeDirection::N));
+        assert_eq!(playing.play_card(BridgeDirection::N, plays[3]), Ok(()));
+
+        // Taking back the last card reopens the play
+        let mut completed = BoardPlay::replay(board(), &bids, &plays).unwrap();
+        assert!(completed.undo());
+        assert!(matches!(completed.state, BoardState::Playing(..)));
+        assert_eq!(completed.score(), None);
+        assert_eq!(completed.tricks_taken, 0);
+        let last = plays[plays.len() - 1];
+        assert_eq!(completed.play_card(BridgeDirection::W, last), Ok(()));
+        assert_eq!(completed.score(), Some(1510));
+
+        // A result entered directly is withdrawn on its own
+        let mut entered = BoardPlay::replay(board(), &bids, &plays[..1]).unwrap();
+        let contract = entered.contract.unwrap();
+        entered.set_result(Contract::PassedOut, 0).unwrap();
+        assert!(entered.undo());
+        assert!(matches!(entered.state, BoardState::Playing(..)));
+        assert_eq!(entered.contract, Some(contract));
+    }
+
+    #[test]
+    fn claim() {
+        let mut board_play = BoardPlay::new();
+        assert_eq!(
+            board_play.claim(BridgeDirection::N, 13).err(),
+            Some(StateError::NotStarted)
+        );
+
+        let board = suited_board;
+        let bids = [SEVEN_SPADES, PASS, PASS, PASS];
+
+        let mut on_lead = BoardPlay::replay(board(), &bids, &[]).unwrap();
+        assert_eq!(
+            on_lead.claim(BridgeDirection::N, 14).err(),
+            Some(StateError::Play(PlayError::TooManyTricksClaimed))
+        );
+        assert!(matches!(on_lead.state, BoardState::OnLead(..)));
+        assert_eq!(on_lead.claim(BridgeDirection::N, 13), Ok(()));
+        assert!(matches!(on_lead.state, BoardState::Completed(_)));
+        assert_eq!(on_lead.tricks_taken, 13);
+        assert_eq!(on_lead.score(), Some(1510));
+        assert_eq!(
+            on_lead.claim(BridgeDirection::N, 13).err(),
+            Some(StateError::Completed)
+        );
+
+        // Withdrawing a claim made before the lead puts the board back on lead
+        assert!(on_lead.undo());
+        assert!(matches!(on_lead.state, BoardState::OnLead(..)));
+        assert_eq!(on_lead.tricks_taken, 0);
+
+        // The defence conceding counts towards declarer's tricks
+        let mut playing = BoardPlay::replay(board(), &bids, &[Card::H2]).unwrap();
+        assert_eq!(playing.claim(BridgeDirection::E, 0), Ok(()));
+        assert_eq!(playing.tricks_taken, 13);
+        assert!(playing.undo());
+        assert!(matches!(playing.state, BoardState::Playing(..)));
+        assert_eq!(playing.score(), None);
+    }
+
+    #[test]
+    fn revoke_policy() {
+        // North holds the spades and East the hearts, except that they swapped the ace of spades for the two of hearts
+        let board = || {
+            let mut board = suited_board();
+            board.north.remove(Card::SA);
+            board.north.insert(Card::H2);
+            board.east.remove(Card::H2);
+            board.east.insert(Card::SA);
+            board
+        };
+        let revoke = |board_play: &mut BoardPlay| {
+            board_play.start_auction().unwrap();
+            for &bid in &[SEVEN_SPADES, PASS, PASS, PASS] {
+                board_play.bid(bid).unwrap();
+            }
+            board_play.play_card(BridgeDirection::E, Card::HA).unwrap();
+            board_play.play_card(BridgeDirection::S, Card::DA).unwrap();
+            board_play.play_card(BridgeDirection::W, Card::CA).unwrap();
+            board_play.play_card(BridgeDirection::N, Card::S2)
+        };
+
+        let mut rejected = BoardPlay::new();
+        rejected.board = board();
+        assert_eq!(
+            revoke(&mut rejected),
+            Err(StateError::Play(PlayError::MustFollowSuit))
+        );
+
+        // North ruffs instead of following with the two of hearts, and wins the trick
+        let mut tracked = BoardPlay::new().with_revoke_policy(RevokePolicy::Track);
+        tracked.board = board();
+        assert_eq!(revoke(&mut tracked), Ok(()));
+        tracked.claim(BridgeDirection::N, 12).unwrap();
+
+        // The revoke trick goes to East-West, so the grand slam is one down
+        assert_eq!(tracked.tricks_taken, 12);
+        assert_eq!(tracked.score(), Some(-50));
+    }
+
+    #[test]
+    fn cardplay() {
+        let mut board_play = BoardPlay::new();
+        assert!(board_play.cardplay().is_none());
+        board_play.start_auction().unwrap();
+        assert!(board_play.cardplay().is_none());
+
+        let passed_out = BoardPlay::replay(Board::new(3), &[PASS, PASS, PASS, PASS], &[]).unwrap();
+        assert!(passed_out.cardplay().is_none());
+
+        let bids = [SEVEN_SPADES, PASS, PASS, PASS];
+        let mut board_play = BoardPlay::replay(suited_board(), &bids, &[]).unwrap();
+        assert_eq!(board_play.cardplay().unwrap().opening_lead(), None);
+        board_play.claim(BridgeDirection::N, 13).unwrap();
+        assert_eq!(board_play.cardplay().unwrap().tricks_for_declarer(), 13);
+    }
+
+    /// Deal number 1, where North holds the spades, East the hearts, South the diamonds and West the clubs.
+    pub(crate) fn suited_board() -> Board {
+        Board::from_big_deal_number(1, 1)
+    }
+
+    /// The 52 cards of a spade contract by North on [`suited_board`], in the order they are played.
+    ///
+    /// East leads a heart, which North ruffs, and North then leads every trick. Each player plays their cards in the same order.
+    fn seven_spades_plays() -> Vec<Card> {
+        let board = suited_board();
+        let mut hands = [board.north, board.east, board.south, board.west];
+        turns(BridgeDirection::E)
+            .take(4)
+            .chain(turns(BridgeDirection::N).take(4 * 12))
+            .map(|who| hands[who as usize].next().unwrap())
+            .collect()
+    }
+
+    /// A board on lead after 1C by North, for tests that only need the auction to be over.
+    fn on_lead(board: &Board) -> BoardState {
+        let auction = Auction::from_notation(BridgeDirection::N, "1C-P-P-P").unwrap();
+        let cardplay = Cardplay::start(board, auction.bid_contract().unwrap());
+        BoardState::OnLead(auction, cardplay)
+    }
+
+    #[test]
+    fn passed_out_board() {
+        let mut board_play = BoardPlay::new();
+        board_play.start_auction().unwrap();
+        for _ in 0..4 {
+            board_play.bid(PASS).unwrap();
+        }
+        assert!(matches!(board_play.state, BoardState::Completed(_)));
+        assert_eq!(board_play.score(), Some(0));
+    }
+
+    #[test]
+    fn replay() {
+        let passed_out = BoardPlay::replay(Board::new(3), &[PASS, PASS, PASS, PASS], &[]).unwrap();
+        assert!(matches!(passed_out.state, BoardState::Completed(_)));
+        assert_eq!(passed_out.score(), Some(0));
+        assert_eq!(
+            BoardPlay::replay(Board::new(3), &[PASS, PASS, PASS, PASS], &[Card::SA]).err(),
+            Some(ReplayError::Play(0, StateError::Completed))
+        );
+
+        let board = suited_board;
+        let bids = [SEVEN_SPADES, PASS, PASS, PASS];
+        let plays = seven_spades_plays();
+
+        let played = BoardPlay::replay(board(), &bids, &plays).unwrap();
+        assert!(matches!(played.state, BoardState::Completed(_)));
+        assert_eq!(played.tricks_taken, 13);
+        assert_eq!(played.score(), Some(1510));
+
+        let on_lead = BoardPlay::replay(board(), &bids, &[]).unwrap();
+        assert_eq!(on_lead.opening_leader(), Some(BridgeDirection::E));
+
+        assert_eq!(
+            BoardPlay::replay(board(), &[SEVEN_SPADES, ONE_CLUB], &[]).err(),
+            Some(ReplayError::Bid(
+                1,
+                StateError::Bid(auction::Error::InsufficientBid)
+            ))
+        );
+
+        let error = BoardPlay::replay(board(), &[SEVEN_SPADES, ONE_CLUB], &[])
+            .err()
+            .expect("The second bid is insufficient");
+        assert_eq!(error.to_string(), "bid #2: bid rejected: insufficient bid");
+        let source = std::error::Error::source(&error).expect("The bid was rejected");
+        assert_eq!(source.to_string(), "bid rejected: insufficient bid");
+        assert_eq!(
+            BoardPlay::replay(board(), &bids, &plays[..5])
+                .map(|b| b.tricks_taken)
+                .ok(),
+            Some(0)
+        );
+        assert_eq!(
+            BoardPlay::replay(board(), &bids, &[Card::HA, Card::HK]).err(),
+            Some(ReplayError::Play(
+                1,
+                StateError::Play(PlayError::CardNotHeld)
+            ))
+        );
+    }
+
+    #[test]
+    fn declaring_side() {
+        let mut board_play = BoardPlay::new();
+        assert_eq!(board_play.declaring_side(), None);
+
+        board_play.contract = Some(Contract::PassedOut);
+        assert_eq!(board_play.declaring_side(), None);
+
+        board_play.contract = Some(Contract::BidContract(BidContract {
+            contract: "2d".try_into().unwrap(),
+            modifier: Modifier::Pass,
+            declarer: BridgeDirection::W,
+        }));
+        assert_eq!(board_play.declaring_side(), Some(Partnership::EW));
+    }
+
+    #[test]
+    fn hand_record() {
+        let mut board_play = BoardPlay::new();
+        board_play.board = Board::from_big_deal_number(5, 1);
+        let record = board_play.hand_record();
+        assert!(record.starts_with("Board 5\nDealer: N\nVulnerable: N-S\n"));
+        assert!(record.contains("            ♠ AKQJT98765432\n"));
+        assert!(record.contains("♠ -                     ♠ -\n"));
+        assert!(record.contains("♥ -                     ♥ AKQJT98765432\n"));
+        assert!(record.contains("            ♦ AKQJT98765432\n"));
+        assert!(record.contains("♣ AKQJT98765432         ♣ -\n"));
+        assert!(!record.contains("Contract"));
+
+        board_play.state = on_lead(&board_play.board);
+        let contract = Contract::BidContract(BidContract {
+            contract: "4h".try_into().unwrap(),
+            modifier: Modifier::Double,
+            declarer: BridgeDirection::E,
+        });
+        board_play.set_result(contract, 9).unwrap();
+        let record = board_play.hand_record();
+        assert!(record.ends_with("Contract: 4Hx E\nTricks: 9\nScore (N-S): 100\n"));
+    }
+
+    #[test]
+    fn imp_vs() {
+        let mut board_play = BoardPlay::new();
+        board_play.state = on_lead(&board_play.board);
+        assert_eq!(board_play.imp_vs(-100), None);
+
+        let four_spades = |declarer| {
+            Contract::BidContract(BidContract {
+                contract: "4s".try_into().unwrap(),
+                modifier: Modifier::Pass,
+                declarer,
+            })
+        };
+        board_play.board = Board::new(7);
+        board_play
+            .set_result(four_spades(BridgeDirection::S), 10)
+            .unwrap();
+        assert_eq!(board_play.score(), Some(620));
+        assert_eq!(board_play.imp_vs(-100), Some(12));
+        assert_eq!(board_play.imp_vs(620), Some(0));
+        assert_eq!(board_play.imp_vs(650), Some(-1));
+
+        let mut board_play = BoardPlay::new();
+        board_play.board = Board::new(7);
+        board_play.state = on_lead(&board_play.board);
+        board_play
+            .set_result(four_spades(BridgeDirection::W), 10)
+            .unwrap();
+        assert_eq!(board_play.score(), Some(-620));
+        assert_eq!(board_play.imp_vs(100), Some(-12));
+    }
+
+    #[test]
+    fn seat_navigation() {
+        use BridgeDirection::*;
+
+        for &(who, partner, lho, rho) in
+            [(N, S, E, W), (E, W, S, N), (S, N, W, E), (W, E, N, S)].iter()
+        {
+            assert_eq!(who.partner(), partner);
+            assert_eq!(who.lho(), lho);
+            assert_eq!(who.rho(), rho);
+            assert!(who.is_partner_of(partner));
+            assert!(!who.is_partner_of(who));
+            assert!(!who.is_partner_of(lho));
+            assert!(who.is_opponent_of(lho));
+            assert!(who.is_opponent_of(rho));
+            assert!(!who.is_opponent_of(partner));
+            assert!(!who.is_opponent_of(who));
+        }
+    }
+
+    #[test]
+    fn all_directions() {
+        let all = BridgeDirection::all();
+        assert_eq!(all.len(), 4);
+        for (i, who) in all.iter().enumerate() {
+            assert!(!all[..i].contains(who));
+        }
+        assert!(BridgeDirection::iter().eq(all.iter().copied()));
+    }
+
+    #[test]
+    fn partnership() {
+        use BridgeDirection::*;
+
+        assert!(Partnership::NS.contains(N));
+        assert!(Partnership::NS.contains(S));
+        assert!(!Partnership::NS.contains(E));
+        assert!(Partnership::EW.contains(W));
+        assert!(!Partnership::EW.contains(S));
+        for who in BridgeDirection::iter() {
+            assert!(Partnership::of(who).contains(who));
+            assert!(!Partnership::of(who).opponents().contains(who));
+        }
+    }
+
+    #[test]
+    fn seat_order() {
+        use crate::seat_order;
+        use BridgeDirection::*;
+
+        assert_eq!(seat_order(N), [N, E, S, W]);
+        assert_eq!(seat_order(E), [E, S, W, N]);
+        assert_eq!(seat_order(S), [S, W, N, E]);
+        assert_eq!(seat_order(W), [W, N, E, S]);
+        for from in BridgeDirection::iter() {
+            assert!(turns(from).take(4).eq(seat_order(from).iter().copied()));
+        }
+    }
+
+    #[test]
+    fn board_schedule() {
+        use BridgeDirection::*;
+        use Vulnerability::*;
+
+        #[rustfmt::skip]
+        let chart = [
+            (N, NONE), (E, NS), (S, EW), (W, ALL),
+            (N, NS), (E, EW), (S, ALL), (W, NONE),
+            (N, EW), (E, ALL), (S, NONE), (W, NS),
+            (N, ALL), (E, NONE), (S, NS), (W, EW),
+        ];
+        for (index, &(dealer, vulnerability)) in chart.iter().enumerate() {
+            let number = index + 1;
+            assert_eq!(dealer_for(number), dealer);
+            assert_eq!(vulnerability_for(number), vulnerability);
+            assert_eq!(dealer_for(number + 16), dealer);
+            assert_eq!(vulnerability_for(number + 16), vulnerability);
+        }
+        assert_eq!(Board::new(7).dealer(), dealer_for(7));
+        assert_eq!(Board::new(7).vulnerability(), vulnerability_for(7));
+    }
+
+    #[test]
+    fn board_numbers() {
+        assert_eq!(Board::try_new(0).err(), Some(DealError::InvalidBoardNumber));
+        assert_eq!(Board::try_new(1).map(|board| board.number), Ok(1));
+
+        for &number in [16, 32].iter() {
+            let board = Board::try_new(number).unwrap();
+            assert_eq!(board.dealer(), BridgeDirection::W);
+            assert_eq!(board.vulnerability(), Vulnerability::EW);
+        }
+    }
+
+    #[test]
+    fn session() {
+        let session = Board::session(24);
+        assert_eq!(session.len(), 24);
+        for (index, board) in session.iter().enumerate() {
+            assert_eq!(board.number, index + 1);
+            assert_eq!(board.vulnerability(), vulnerability_for(index + 1));
+            assert_eq!(board.dealer(), dealer_for(index + 1));
+        }
+        assert_eq!(session[0].vulnerability(), Vulnerability::NONE);
+        assert_eq!(session[16].vulnerability(), Vulnerability::NONE);
+        assert_eq!(session[3].vulnerability(), Vulnerability::ALL);
+
+        let seeded = Board::session_from_seed(32, 2021);
+        let again = Board::session_from_seed(32, 2021);
+        assert_eq!(seeded.len(), 32);
+        for (board, other) in seeded.iter().zip(again.iter()) {
+            assert_eq!(board.number, other.number);
+            assert_eq!(board.north, other.north);
+            assert_eq!(board.west, other.west);
+        }
+        assert_ne!(seeded[0].north, seeded[1].north);
+        assert_eq!(seeded[31].vulnerability(), Vulnerability::EW);
+    }
+
+    #[test]
+    fn vulnerable_sides() {
+        use Partnership::*;
+
+        for &(vulnerability, sides) in [
+            (Vulnerability::NONE, &[][..]),
+            (Vulnerability::NS, &[NS][..]),
+            (Vulnerability::EW, &[EW][..]),
+            (Vulnerability::ALL, &[NS, EW][..]),
+        ]
+        .iter()
+        {
+            assert_eq!(vulnerability.vulnerable_sides(), sides);
+            for &side in [NS, EW].iter() {
+                assert_eq!(
+                    vulnerability.is_side_vulnerable(side),
+                    sides.contains(&side)
+                );
+                for who in BridgeDirection::iter().filter(|&who| side.contains(who)) {
+                    assert_eq!(
+                        vulnerability.is_vulnerable(who),
+                        vulnerability.is_side_vulnerable(side)
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,537 @@
+//! Scoring helpers
+//!
+//! Converts the raw scores of a board, as returned by [`BoardPlay::score`](crate::BoardPlay::score), into match points such as IMPs, and keeps track of rubber bridge figures.
+
+use crate::auction::StrainBid;
+use crate::contract::{BidContract, Contract, ContractLevel, Modifier, Strain};
+use crate::{BridgeDirection, Partnership, Vulnerability};
+use std::fmt;
+
+/// The highest point difference worth each number of IMPs, according to the WBF scale.
+///
+/// A difference above the last entry is worth 24 IMPs.
+const IMP_SCALE: [i32; 24] = [
+    10, 40, 80, 120, 160, 210, 260, 310, 360, 420, 490, 590, 740, 890, 1090, 1290, 1490, 1740,
+    1990, 2240, 2490, 2990, 3490, 3990,
+];
+
+/// Converts a point difference between two results into IMPs, keeping its sign.
+///
+/// ```
+/// use bridge_backend::scoring::imps_from_difference;
+///
+/// assert_eq!(imps_from_difference(0), 0);
+/// assert_eq!(imps_from_difference(720), 12);
+/// assert_eq!(imps_from_difference(-50), -2);
+/// assert_eq!(imps_from_difference(5000), 24);
+/// ```
+pub fn imps_from_difference(difference: i32) -> i32 {
+    let imps = IMP_SCALE
+        .iter()
+        .take_while(|&&limit| difference.abs() > limit)
+        .count() as i32;
+    imps * difference.signum()
+}
+
+/// Scores a whole field using cross-IMPs.
+///
+/// Each result is compared against every other result on the board, and the IMPs are averaged over the number of comparisons. Since every comparison is counted once in each direction, the cross-IMPs of a field always add up to zero.
+///
+/// A field with a single result has nothing to be compared against, so it scores 0.
+pub fn cross_imp(scores: &[i32]) -> Vec<f32> {
+    let comparisons = scores.len().saturating_sub(1).max(1) as f32;
+    scores
+        .iter()
+        .map(|&score| {
+            let total: i32 = scores
+                .iter()
+                .map(|&other| imps_from_difference(score - other))
+                .sum();
+            total as f32 / comparisons
+        })
+        .collect()
+}
+
+/// Returns how many trick points a side still needs below the line to make game in rubber bridge.
+///
+/// ```
+/// use bridge_backend::scoring::points_to_game;
+///
+/// assert_eq!(points_to_game(60), 40);
+/// assert_eq!(points_to_game(120), 0);
+/// ```
+pub fn points_to_game(below_line: usize) -> usize {
+    100usize.saturating_sub(below_line)
+}
+
+/// The score sheet of a rubber bridge game.
+///
+/// Points for the tricks bid are scored below the line, and count towards game: the first side to reach 100 of them wins a game, and a new one starts with both sides back at 0 below the line. Everything else is scored above the line: overtricks, slam and insult bonuses, and penalties for undertricks.
+///
+/// A side becomes vulnerable once it has won a game, and the rubber ends when a side wins its second game. That side then gets a rubber bonus of 700, or 500 if the opponents also won a game.
+#[derive(Debug, Default)]
+pub struct Rubber {
+    trick_points: [usize; 2],
+    game_in_progress: [usize; 2],
+    above_the_line: [i32; 2],
+    games: [usize; 2],
+}
+
+impl Rubber {
+    /// Starts a new rubber, with an empty score sheet.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns which sides are vulnerable, i.e. have already won a game.
+    pub fn vulnerability(&self) -> Vulnerability {
+        match self.games {
+            [0, 0] => Vulnerability::NONE,
+            [_, 0] => Vulnerability::NS,
+            [0, _] => Vulnerability::EW,
+            _ => Vulnerability::ALL,
+        }
+    }
+
+    /// Returns the points a side has below the line in the game in progress, i.e. towards its next game.
+    ///
+    /// Points scored in games already won still count in the [`totals`](Rubber::totals), but not here.
+    ///
+    /// ```
+    /// use bridge_backend::scoring::{points_to_game, Rubber};
+    /// use bridge_backend::Partnership;
+    ///
+    /// let mut rubber = Rubber::new();
+    /// rubber.record(&"2NT S".parse().unwrap(), 8).unwrap();
+    /// assert_eq!(rubber.below_the_line(Partnership::NS), 70);
+    /// assert_eq!(points_to_game(rubber.below_the_line(Partnership::NS)), 30);
+    /// ```
+    pub fn below_the_line(&self, side: Partnership) -> usize {
+        self.game_in_progress[side as usize]
+    }
+
+    /// Returns true once a side has won two games.
+    pub fn is_finished(&self) -> bool {
+        self.games.contains(&2)
+    }
+
+    /// Records the result of a board.
+    ///
+    /// Unlike duplicate boards, the vulnerability isn't passed in: in rubber bridge it only depends on the games won so far, see [`vulnerability`](Rubber::vulnerability), so it can't disagree with the score sheet.
+    ///
+    /// Returns an error, leaving the score sheet untouched, if the rubber is already finished.
+    pub fn record(
+        &mut self,
+        contract: &BidContract,
+        tricks_taken: usize,
+    ) -> Result<(), RubberError> {
+        if self.is_finished() {
+            return Err(RubberError::Finished);
+        }
+
+        let declarer = contract.declarer;
+        let declaring_side = Partnership::of(declarer) as usize;
+        let vulnerability = self.vulnerability();
+        let vulnerable = vulnerability.is_vulnerable(declarer);
+
+        let result = contract.result(tricks_taken);
+        if result < 0 {
+            let penalty =
+                -Contract::BidContract(*contract).get_score_for_tricks(tricks_taken, vulnerability);
+            self.above_the_line[1 - declaring_side] += penalty;
+            return Ok(());
+        }
+
+        let overtricks = result as usize;
+        let overtrick_points = contract.overtrick_points(overtricks, vulnerable) as i32;
+        self.above_the_line[declaring_side] +=
+            overtrick_points + contract.insult_bonus() + contract.slam_bonus(vulnerable);
+
+        let trick_points = contract.trick_points();
+        self.trick_points[declaring_side] += trick_points;
+        self.game_in_progress[declaring_side] += trick_points;
+        if self.game_in_progress[declaring_side] >= 100 {
+            self.game_in_progress = [0, 0];
+            self.games[declaring_side] += 1;
+            if self.games[declaring_side] == 2 {
+                let rubber_bonus = if self.games[1 - declaring_side] == 0 {
+                    700
+                } else {
+                    500
+                };
+                self.above_the_line[declaring_side] += rubber_bonus;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the total points of North-South and East-West, above and below the line.
+    pub fn totals(&self) -> (i32, i32) {
+        let total = |side: usize| self.above_the_line[side] + self.trick_points[side] as i32;
+        (
+            total(Partnership::NS as usize),
+            total(Partnership::EW as usize),
+        )
+    }
+
+    /// Closes the score sheet, and returns the final totals.
+    ///
+    /// A finished rubber already holds its rubber bonus. When the rubber ends before a side wins two games, each side gets 300 for a game won in the unfinished rubber, and 50 for a partscore in the game in progress.
+    pub fn finalize(self) -> RubberResult {
+        let finished = self.is_finished();
+        let (mut north_south, mut east_west) = self.totals();
+        if !finished {
+            let bonus = |side: usize| {
+                let game = if self.games[side] == 1 { 300 } else { 0 };
+                let partscore = if self.game_in_progress[side] > 0 {
+                    50
+                } else {
+                    0
+                };
+                game + partscore
+            };
+            north_south += bonus(Partnership::NS as usize);
+            east_west += bonus(Partnership::EW as usize);
+        }
+        RubberResult {
+            north_south,
+            east_west,
+            finished,
+        }
+    }
+}
+
+/// The final score of a rubber, see [`Rubber::finalize`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RubberResult {
+    /// The total points of North-South
+    pub north_south: i32,
+    /// The total points of East-West
+    pub east_west: i32,
+    /// Whether a side won two games, rather than the rubber being cut short
+    pub finished: bool,
+}
+
+/// Errors when keeping the score of a rubber.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RubberError {
+    /// A side has already won two games
+    Finished,
+}
+
+impl fmt::Display for RubberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RubberError::Finished => f.write_str("the rubber is already finished"),
+        }
+    }
+}
+
+impl std::error::Error for RubberError {}
+
+/// The number of tricks each player can take as declarer in each strain, e.g. from a double dummy analysis.
+///
+/// The outer array is indexed by [`BridgeDirection`], the inner one by [`Strain`], from clubs up to notrump.
+pub type MakeableTricks = [[usize; 5]; 4];
+
+/// The par result of a board, see [`par`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParResult {
+    /// The par contract, or [`Contract::PassedOut`] when neither side can score by bidding
+    pub contract: Contract,
+    /// The par score, from the perspective of North-South
+    pub score: i32,
+}
+
+/// Works out the par contract and score of a board: the result when both sides bid perfectly, knowing how many tricks every contract makes.
+///
+/// A side bids higher whenever that improves its score, including sacrifices: a contract that goes down, but costs less than letting the opponents play theirs. Contracts that make are played undoubled, and contracts that go down are doubled.
+/// Each contract is played by the member of the side who makes more tricks in its strain. When several contracts reach the par score, the lowest one is reported.
+pub fn par(makeable: &MakeableTricks, vulnerability: Vulnerability) -> ParResult {
+    let bids: Vec<StrainBid> = ContractLevel::all()
+        .flat_map(|level| Strain::all().map(move |strain| StrainBid { level, strain }))
+        .collect();
+    let bid_count = bids.len();
+    let mut search = ParSearch {
+        makeable,
+        vulnerability,
+        bids,
+        outcomes: vec![None; 2 * bid_count],
+    };
+
+    // If North-South pass, East-West may open, or pass the board out
+    let passed_out = ParResult {
+        contract: Contract::PassedOut,
+        score: 0,
+    };
+    let east_west_open = (0..bid_count)
+        .map(|bid| search.outcome(bid, Partnership::EW))
+        .fold(passed_out, |best, outcome| {
+            if outcome.score < best.score {
+                outcome
+            } else {
+                best
+            }
+        });
+    (0..bid_count)
+        .map(|bid| search.outcome(bid, Partnership::NS))
+        .fold(east_west_open, |best, outcome| {
+            if outcome.score > best.score {
+                outcome
+            } else {
+                best
+            }
+        })
+}
+
+/// Finds the outcome of each contract under perfect bidding, remembering the outcomes already found.
+struct ParSearch<'a> {
+    makeable: &'a MakeableTricks,
+    vulnerability: Vulnerability,
+    bids: Vec<StrainBid>,
+    outcomes: Vec<Option<ParResult>>,
+}
+
+impl ParSearch<'_> {
+    /// The result if a side's contract is the final one, doubled when it goes down.
+    fn played(&self, bid: usize, side: Partnership) -> ParResult {
+        let strain = self.bids[bid].strain;
+        let tricks = |who: BridgeDirection| self.makeable[who as usize][strain as usize];
+        let (first, second) = match side {
+            Partnership::NS => (BridgeDirection::N, BridgeDirection::S),
+            Partnership::EW => (BridgeDirection::E, BridgeDirection::W),
+        };
+        let declarer = if tricks(second) > tricks(first) {
+            second
+        } else {
+            first
+        };
+
+        let mut contract = BidContract {
+            contract: self.bids[bid],
+            modifier: Modifier::Pass,
+            declarer,
+        };
+        if tricks(declarer) < contract.tricks_needed() {
+            contract.modifier = Modifier::Double;
+        }
+        let contract = Contract::BidContract(contract);
+        let score = contract.get_score_for_tricks(tricks(declarer), self.vulnerability);
+        ParResult {
+            contract,
+            score: if side == Partnership::NS {
+                score
+            } else {
+                -score
+            },
+        }
+    }
+
+    /// The result once a side has bid a contract, and the opponents may either let it stand or bid higher.
+    fn outcome(&mut self, bid: usize, side: Partnership) -> ParResult {
+        let key = 2 * bid + side as usize;
+        if let Some(outcome) = self.outcomes[key] {
+            return outcome;
+        }
+
+        let opponents = side.opponents();
+        let mut best = self.played(bid, side);
+        for higher in bid + 1..self.bids.len() {
+            let outcome = self.outcome(higher, opponents);
+            let improves = match opponents {
+                Partnership::NS => outcome.score > best.score,
+                Partnership::EW => outcome.score < best.score,
+            };
+            if improves {
+                best = outcome;
+            }
+        }
+
+        self.outcomes[key] = Some(best);
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::{BidContract, Contract, Modifier};
+    use crate::scoring::{
+        cross_imp, imps_from_difference, par, points_to_game, MakeableTricks, Rubber, RubberError,
+        RubberResult,
+    };
+    use crate::{BridgeDirection, Partnership, Vulnerability};
+    use std::convert::TryInto;
+
+    #[test]
+    fn imp_scale_boundaries() {
+        assert_eq!(imps_from_difference(10), 0);
+        assert_eq!(imps_from_difference(20), 1);
+        assert_eq!(imps_from_difference(490), 10);
+        assert_eq!(imps_from_difference(500), 11);
+        assert_eq!(imps_from_difference(3990), 23);
+        assert_eq!(imps_from_difference(4000), 24);
+        assert_eq!(imps_from_difference(-4000), -24);
+    }
+
+    #[test]
+    fn cross_imps_of_small_field() {
+        let field = [620, 620, 170, -100];
+        let imps = cross_imp(&field);
+
+        // 620 gains 0, 10 and 12 IMPs against the other three results
+        assert!((imps[0] - 22.0 / 3.0).abs() < 1e-6);
+        assert_eq!(imps[0], imps[1]);
+        // -100 loses 12, 12 and 7 IMPs
+        assert!((imps[3] + 31.0 / 3.0).abs() < 1e-6);
+
+        let total: f32 = imps.iter().sum();
+        assert!(total.abs() < 1e-4);
+    }
+
+    #[test]
+    fn cross_imps_of_tiny_fields() {
+        assert!(cross_imp(&[]).is_empty());
+        assert_eq!(cross_imp(&[420]), vec![0.0]);
+        assert_eq!(cross_imp(&[420, -100]), vec![11.0, -11.0]);
+    }
+
+    #[test]
+    fn partscore_towards_game() {
+        assert_eq!(points_to_game(0), 100);
+        assert_eq!(points_to_game(70), 30);
+        assert_eq!(points_to_game(100), 0);
+
+        let mut rubber = Rubber::new();
+        rubber.record(&"2NT S".parse().unwrap(), 8).unwrap();
+        assert_eq!(points_to_game(rubber.below_the_line(Partnership::NS)), 30);
+        assert_eq!(points_to_game(rubber.below_the_line(Partnership::EW)), 100);
+    }
+
+    #[test]
+    fn rubber() {
+        let mut rubber = Rubber::new();
+        assert_eq!(rubber.vulnerability(), Vulnerability::NONE);
+
+        // A partscore of 60 for North-South, wiped out when East-West make game
+        rubber.record(&"2S N".parse().unwrap(), 8).unwrap();
+        rubber.record(&"3NT E".parse().unwrap(), 9).unwrap();
+        assert_eq!(rubber.vulnerability(), Vulnerability::EW);
+        assert_eq!(rubber.below_the_line(Partnership::NS), 0);
+        assert_eq!(rubber.below_the_line(Partnership::EW), 0);
+
+        // 120 below the line and an overtrick above it
+        rubber.record(&"4H S".parse().unwrap(), 11).unwrap();
+        assert_eq!(rubber.vulnerability(), Vulnerability::ALL);
+        assert!(!rubber.is_finished());
+
+        // Two down, doubled and vulnerable
+        rubber.record(&"2Cx W".parse().unwrap(), 6).unwrap();
+        assert_eq!(rubber.totals(), (60 + 120 + 30 + 500, 100));
+
+        // The deciding game, with an overtrick and the rubber bonus
+        rubber.record(&"3NT N".parse().unwrap(), 10).unwrap();
+        assert!(rubber.is_finished());
+        assert_eq!(rubber.totals(), (60 + 120 + 30 + 500 + 100 + 30 + 500, 100));
+
+        // Nothing more is scored once the rubber is over
+        assert_eq!(
+            rubber.record(&"7NT E".parse().unwrap(), 13),
+            Err(RubberError::Finished)
+        );
+        assert_eq!(rubber.totals(), (60 + 120 + 30 + 500 + 100 + 30 + 500, 100));
+    }
+
+    #[test]
+    fn two_game_rubber() {
+        let mut rubber = Rubber::new();
+        rubber.record(&"6S W".parse().unwrap(), 12).unwrap();
+        rubber.record(&"2Hx E".parse().unwrap(), 9).unwrap();
+        assert!(rubber.is_finished());
+        // 180 for the slam, 500 slam bonus, 120 doubled partscore game, 200 for the doubled overtrick, 50 insult and 700 rubber bonus
+        assert_eq!(rubber.totals(), (0, 180 + 500 + 120 + 200 + 50 + 700));
+        assert_eq!(
+            rubber.finalize(),
+            RubberResult {
+                north_south: 0,
+                east_west: 1750,
+                finished: true,
+            }
+        );
+    }
+
+    #[test]
+    fn unfinished_rubber() {
+        let mut rubber = Rubber::new();
+        assert_eq!(
+            Rubber::new().finalize(),
+            RubberResult {
+                north_south: 0,
+                east_west: 0,
+                finished: false,
+            }
+        );
+
+        // East-West have a game, North-South a partscore of 60 in the next one
+        rubber.record(&"4S E".parse().unwrap(), 10).unwrap();
+        rubber.record(&"2S N".parse().unwrap(), 8).unwrap();
+        assert_eq!(
+            rubber.finalize(),
+            RubberResult {
+                north_south: 60 + 50,
+                east_west: 120 + 300,
+                finished: false,
+            }
+        );
+    }
+
+    // Clubs, diamonds, hearts, spades and notrump
+    const SPADES_FOR_NS: MakeableTricks = [
+        [6, 6, 4, 10, 7],
+        [5, 5, 9, 3, 5],
+        [6, 6, 4, 10, 7],
+        [5, 5, 9, 3, 5],
+    ];
+
+    #[test]
+    fn par_game() {
+        let mut makeable = SPADES_FOR_NS;
+        makeable[1][2] = 6;
+        makeable[3][2] = 6;
+        let result = par(&makeable, Vulnerability::NS);
+        assert_eq!(result.score, 620);
+        assert_eq!(
+            result.contract,
+            Contract::BidContract(BidContract {
+                contract: "4s".try_into().unwrap(),
+                modifier: Modifier::Pass,
+                declarer: BridgeDirection::N,
+            })
+        );
+    }
+
+    #[test]
+    fn par_sacrifice() {
+        // Letting North-South make 4S costs 420, while 5H doubled is only two down
+        let result = par(&SPADES_FOR_NS, Vulnerability::NONE);
+        assert_eq!(result.score, 300);
+        assert_eq!(
+            result.contract,
+            Contract::BidContract(BidContract {
+                contract: "5h".try_into().unwrap(),
+                modifier: Modifier::Double,
+                declarer: BridgeDirection::E,
+            })
+        );
+
+        // Vulnerable, the same sacrifice costs 500, more than the game
+        let result = par(&SPADES_FOR_NS, Vulnerability::EW);
+        assert_eq!(result.score, 420);
+    }
+
+    #[test]
+    fn par_passed_out() {
+        let result = par(&[[6; 5]; 4], Vulnerability::ALL);
+        assert_eq!(result.contract, Contract::PassedOut);
+        assert_eq!(result.score, 0);
+    }
+}